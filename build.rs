@@ -4,13 +4,22 @@ use substreams_ethereum::Abigen;
 use std::fs;
 
 fn main() -> Result<(), anyhow::Error> {
+    // factory + lender group ABIs of the v1 factory generation, then the token, TellerV2 and Uniswap V3 pool ABIs
     let file_names = [
         "abi/factory_contract.abi.json",
         "abi/lendergroup_contract.abi.json",
+        "abi/erc20.abi.json",
+        "abi/erc20_bytes32.abi.json",
+        "abi/teller_v2.abi.json",
+        "abi/uniswap_v3_pool.abi.json",
     ];
     let file_output_names = [
         "src/abi/factory_contract.rs",
         "src/abi/lendergroup_contract.rs",
+        "src/abi/erc20.rs",
+        "src/abi/erc20_bytes32.rs",
+        "src/abi/teller_v2.rs",
+        "src/abi/uniswap_v3_pool.rs",
     ];
 
     let mut i = 0;
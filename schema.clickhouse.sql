@@ -3,6 +3,8 @@ CREATE TABLE IF NOT EXISTS factory_admin_changed (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "new_admin" VARCHAR(40),
     "previous_admin" VARCHAR(40)
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
@@ -11,6 +13,8 @@ CREATE TABLE IF NOT EXISTS factory_beacon_upgraded (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "beacon" VARCHAR(40)
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS factory_deployed_lender_group_contract (
@@ -18,13 +22,29 @@ CREATE TABLE IF NOT EXISTS factory_deployed_lender_group_contract (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
-    "group_contract" VARCHAR(40)
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "group_contract" VARCHAR(40),
+    "deployer" VARCHAR(40),
+    "initial_principal_amount" UInt256,
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
+    "market_id" UInt256,
+    "max_loan_duration" UInt32,
+    "interest_rate_lower_bound" UInt16,
+    "interest_rate_upper_bound" UInt16,
+    "liquidity_threshold_percent" UInt16,
+    "loan_to_value_percent" UInt16,
+    "uniswap_pool_fee" UInt32,
+    "twap_interval" UInt32
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS factory_upgraded (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "implementation" VARCHAR(40)
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 
@@ -33,41 +53,81 @@ CREATE TABLE IF NOT EXISTS lendergroup_borrower_accepted_funds (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "bid_id" UInt256,
     "borrower" VARCHAR(40),
     "collateral_amount" UInt256,
     "interest_rate" UInt16,
     "loan_duration" UInt32,
-    "principal_amount" UInt256
+    "principal_amount" UInt256,
+    "principal_amount_normalized" Float64,
+    "collateral_amount_normalized" Float64,
+    "market_id" UInt256,
+    "payment_cycle_amount" UInt256,
+    "payment_cycle" UInt32,
+    "apr" UInt16,
+    "payment_type" VARCHAR(16),
+    "payment_cycle_type" VARCHAR(16),
+    "bid_accepted_at" TIMESTAMP,
+    "loan_due_date" TIMESTAMP,
+    "first_payment_due_date" TIMESTAMP,
+    "default_duration" UInt32,
+    "bid_rpc_enrichment_status" VARCHAR(12),
+    "twap_interval" UInt32,
+    "sqrt_twap_x96" UInt256,
+    "average_weighted_price" UInt256,
+    "required_collateral_amount" UInt256,
+    "collateral_excess" Int256,
+    "oracle_rpc_enrichment_status" VARCHAR(12),
+    "oracle_swapped_after_loan" Bool
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS lendergroup_defaulted_loan_liquidated (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "amount_due" UInt256,
     "bid_id" UInt256,
     "liquidator" VARCHAR(40),
-    "token_amount_difference" Int256
+    "token_amount_difference" Int256,
+    "amount_due_normalized" Float64,
+    "token_amount_difference_normalized" Float64
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS lendergroup_earnings_withdrawn (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "amount_pool_shares_tokens" UInt256,
     "lender" VARCHAR(40),
     "principal_tokens_withdrawn" UInt256,
-    "recipient" VARCHAR(40)
+    "recipient" VARCHAR(40),
+    "amount_pool_shares_tokens_normalized" Float64,
+    "principal_tokens_withdrawn_normalized" Float64
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS lendergroup_initialized (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "version" UInt8
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
@@ -76,30 +136,48 @@ CREATE TABLE IF NOT EXISTS lendergroup_lender_added_principal (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "amount" UInt256,
     "lender" VARCHAR(40),
     "shares_amount" UInt256,
-    "shares_recipient" VARCHAR(40)
+    "shares_recipient" VARCHAR(40),
+    "amount_normalized" Float64,
+    "shares_amount_normalized" Float64
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS lendergroup_loan_repaid (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "bid_id" UInt256,
     "interest_amount" UInt256,
     "principal_amount" UInt256,
     "repayer" VARCHAR(40),
     "total_interest_collected" UInt256,
-    "total_principal_repaid" UInt256
+    "total_principal_repaid" UInt256,
+    "principal_amount_normalized" Float64,
+    "interest_amount_normalized" Float64,
+    "total_principal_repaid_normalized" Float64,
+    "total_interest_collected_normalized" Float64
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS lendergroup_ownership_transferred (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "new_owner" VARCHAR(40),
     "previous_owner" VARCHAR(40)
@@ -109,6 +187,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_paused (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "account" VARCHAR(40)
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
@@ -117,6 +199,8 @@ CREATE TABLE IF NOT EXISTS lendergroup_pool_initialized (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "evt_address" VARCHAR(40),
     "collateral_token_address" VARCHAR(40),
     "interest_rate_lower_bound" UInt16,
@@ -128,13 +212,436 @@ CREATE TABLE IF NOT EXISTS lendergroup_pool_initialized (
     "pool_shares_token" VARCHAR(40),
     "principal_token_address" VARCHAR(40),
     "twap_interval" UInt32,
-    "uniswap_pool_fee" UInt32
+    "uniswap_pool_fee" UInt32,
+    "rpc_enrichment_status" VARCHAR(12),
+    "uniswap_v3_pool_address" VARCHAR(40),
+    "teller_v2_address" VARCHAR(40),
+    "smart_commitment_forwarder_address" VARCHAR(40)
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
 CREATE TABLE IF NOT EXISTS lendergroup_unpaused (
     "evt_tx_hash" VARCHAR(64),
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" UInt64,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "account" VARCHAR(40)
 ) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS group_pool_metrics (
+    "group_pool_address" VARCHAR(40),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
+    "shares_token_address" VARCHAR(40),
+    "uniswap_v3_pool_address" VARCHAR(40),
+    "teller_v2_address" VARCHAR(40),
+    "smart_commitment_forwarder_address" VARCHAR(40),
+    "rpc_enrichment_status" VARCHAR(12),
+    "market_id" UInt256,
+    "uniswap_pool_fee" UInt32,
+    "max_loan_duration" UInt32,
+    "twap_interval" UInt32,
+    "interest_rate_upper_bound" UInt16,
+    "interest_rate_lower_bound" UInt16,
+    "liquidity_threshold_percent" UInt16,
+    "collateral_ratio" UInt16,
+    "total_principal_tokens_committed" UInt256,
+    "total_principal_tokens_withdrawn" UInt256,
+    "total_principal_tokens_lended" UInt256,
+    "total_principal_tokens_repaid" UInt256,
+    "total_interest_collected" UInt256,
+    "token_difference_from_liquidations" Int256
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address");
+
+CREATE TABLE IF NOT EXISTS group_lender_metrics (
+    "group_pool_address" VARCHAR(40),
+    "user_lender_address" VARCHAR(40),
+    "total_principal_tokens_committed" UInt256,
+    "total_principal_tokens_withdrawn" UInt256,
+    "shares_tokens_net" Int256
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","user_lender_address");
+
+CREATE TABLE IF NOT EXISTS group_pool_metrics_data_point (
+    "group_pool_address" VARCHAR(40),
+    "interval" VARCHAR(8),
+    "period_start" UInt64,
+    "block_number" UInt64,
+    "block_time" TIMESTAMP,
+    "total_principal_tokens_committed" UInt256,
+    "total_principal_tokens_withdrawn" UInt256,
+    "total_principal_tokens_lended" UInt256,
+    "total_principal_tokens_repaid" UInt256,
+    "total_interest_collected" UInt256,
+    "token_difference_from_liquidations" Int256,
+    "total_principal_tokens_outstanding" Int256,
+    "pool_total_estimated_value" Int256,
+    "utilization_ratio" Float64
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","interval","period_start");
+
+CREATE TABLE IF NOT EXISTS loan (
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256,
+    "status" VARCHAR(16),
+    "borrower" VARCHAR(40),
+    "principal_amount" UInt256,
+    "collateral_amount" UInt256,
+    "loan_duration" UInt32,
+    "interest_rate" UInt16,
+    "accepted_tx_hash" VARCHAR(64),
+    "accepted_block_number" UInt64,
+    "accepted_at" TIMESTAMP,
+    "principal_repaid" UInt256,
+    "interest_repaid" UInt256,
+    "principal_amount_normalized" Float64,
+    "collateral_amount_normalized" Float64,
+    "principal_repaid_normalized" Float64,
+    "interest_repaid_normalized" Float64,
+    "last_repaid_at" TIMESTAMP,
+    "liquidated_at" TIMESTAMP,
+    "liquidator" VARCHAR(40),
+    "liquidation_amount_due" UInt256,
+    "liquidation_token_amount_difference" Int256,
+    "liquidation_amount_due_normalized" Float64,
+    "liquidation_token_amount_difference_normalized" Float64,
+    "updated_block_number" UInt64,
+    "updated_at" TIMESTAMP,
+    "market_id" UInt256,
+    "payment_cycle_amount" UInt256,
+    "payment_cycle" UInt32,
+    "apr" UInt16,
+    "payment_type" VARCHAR(16),
+    "payment_cycle_type" VARCHAR(16),
+    "bid_accepted_at" TIMESTAMP,
+    "loan_due_date" TIMESTAMP,
+    "first_payment_due_date" TIMESTAMP,
+    "default_duration" UInt32,
+    "bid_rpc_enrichment_status" VARCHAR(12)
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","bid_id");
+
+CREATE TABLE IF NOT EXISTS token (
+    "address" VARCHAR(40),
+    "name" VARCHAR(256),
+    "symbol" VARCHAR(64),
+    "decimals" UInt8,
+    "rpc_enrichment_status" VARCHAR(12),
+    "block_number" UInt64
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("address");
+
+CREATE TABLE IF NOT EXISTS share_transfer (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "from" VARCHAR(40),
+    "to" VARCHAR(40),
+    "value" UInt256,
+    "value_normalized" Float64
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS share_holder (
+    "group_pool_address" VARCHAR(40),
+    "holder_address" VARCHAR(40),
+    "balance" Int256
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","holder_address");
+
+CREATE TABLE IF NOT EXISTS share_exchange_rate (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "group_pool_address" VARCHAR(40),
+    "source" VARCHAR(32),
+    "principal_amount" UInt256,
+    "shares_amount" UInt256,
+    "exchange_rate" Float64
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS share_exchange_rate_candle (
+    "group_pool_address" VARCHAR(40),
+    "period_start" UInt64,
+    "open" Float64,
+    "high" Float64,
+    "low" Float64,
+    "close" Float64,
+    "block_number" UInt64,
+    "block_time" TIMESTAMP
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","period_start");
+
+CREATE TABLE IF NOT EXISTS pool_onchain_snapshot (
+    "group_pool_address" VARCHAR(40),
+    "block_number" UInt64,
+    "block_time" TIMESTAMP,
+    "pool_utilization_ratio" UInt16,
+    "shares_exchange_rate" UInt256,
+    "principal_amount_available_to_borrow" UInt256,
+    "total_principal_tokens_committed" UInt256,
+    "total_principal_tokens_outstanding_in_active_loans" UInt256,
+    "total_principal_tokens_lended" UInt256,
+    "total_principal_tokens_repaid" UInt256,
+    "total_interest_collected" UInt256,
+    "total_collateral_tokens_escrowed_for_loans" UInt256,
+    "paused" Bool,
+    "rpc_enrichment_status" VARCHAR(12)
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","block_number");
+
+CREATE TABLE IF NOT EXISTS reconciliation_mismatch (
+    "group_pool_address" VARCHAR(40),
+    "block_number" UInt64,
+    "block_time" TIMESTAMP,
+    "total" VARCHAR(40),
+    "event_derived_value" Int256,
+    "onchain_value" UInt256,
+    "difference" Int256
+) ENGINE = ReplacingMergeTree PRIMARY KEY ("group_pool_address","block_number","total");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_add_principal_to_commitment_group (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "amount" UInt256,
+    "shares_recipient" VARCHAR(40),
+    "output_shares_amount" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_burn_shares_to_withdraw_earnings (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "amount_pool_shares_tokens" UInt256,
+    "recipient" VARCHAR(40),
+    "output_principal_tokens_withdrawn" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_liquidate_defaulted_loan_with_incentive (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "bid_id" UInt256,
+    "token_amount_difference" Int256
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_pause_borrowing (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16)
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_unpause_borrowing (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16)
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_accept_funds_for_accept_bid (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "borrower" VARCHAR(40),
+    "bid_id" UInt256,
+    "principal_amount" UInt256,
+    "collateral_amount" UInt256,
+    "collateral_token_address" VARCHAR(40),
+    "collateral_token_id" UInt256,
+    "loan_duration" UInt32,
+    "interest_rate" UInt16
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_repay_loan_callback (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_success" Bool,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "bid_id" UInt256,
+    "repayer" VARCHAR(40),
+    "principal_amount" UInt256,
+    "interest_amount" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS reverted_call (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" UInt64,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" UInt64,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "tx_from" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "function" VARCHAR(64),
+    "arguments" String,
+    "gas_used" UInt64,
+    "revert_reason" String,
+    "revert_data" String,
+    "failure_reason" String
+) ENGINE = MergeTree PRIMARY KEY ("call_tx_hash","call_ordinal");
+
+CREATE TABLE IF NOT EXISTS teller_v2_submitted_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256,
+    "borrower" VARCHAR(40),
+    "receiver" VARCHAR(40),
+    "metadata_uri" VARCHAR(64)
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_accepted_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256,
+    "lender" VARCHAR(40)
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_cancelled_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_market_owner_cancelled_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_repayment (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_repaid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_liquidated (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256,
+    "liquidator" VARCHAR(40)
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_closed (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" UInt64,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256
+) ENGINE = MergeTree PRIMARY KEY ("evt_tx_hash","evt_index");
+
+CREATE TABLE IF NOT EXISTS collateral_price (
+    "group_pool_address" VARCHAR(40),
+    "evt_block_number" UInt64,
+    "evt_block_time" TIMESTAMP,
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "uniswap_v3_pool_address" VARCHAR(40),
+    "sqrt_price_x96" UInt256,
+    "tick" Int32,
+    "price" Float64,
+    "price_normalized" Float64
+) ENGINE = MergeTree PRIMARY KEY ("group_pool_address","evt_block_number");
+
+CREATE TABLE IF NOT EXISTS loan_health (
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256,
+    "block_number" UInt64,
+    "block_time" TIMESTAMP,
+    "status" VARCHAR(16),
+    "outstanding_principal" UInt256,
+    "collateral_amount" UInt256,
+    "collateral_value" Float64,
+    "ltv_percent" Float64,
+    "price_block_number" UInt64,
+    "collateral_ratio" UInt16,
+    "max_ltv_percent" Float64,
+    "above_max_ltv" Bool,
+    "warning_level" UInt32
+) ENGINE = MergeTree PRIMARY KEY ("group_pool_address","bid_id","block_number");
+
+CREATE TABLE IF NOT EXISTS loan_health_alert (
+    "group_pool_address" VARCHAR(40),
+    "bid_id" UInt256,
+    "block_number" UInt64,
+    "block_time" TIMESTAMP,
+    "previous_warning_level" UInt32,
+    "warning_level" UInt32,
+    "ltv_percent" Float64
+) ENGINE = MergeTree PRIMARY KEY ("group_pool_address","bid_id","block_number");
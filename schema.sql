@@ -3,6 +3,8 @@ CREATE TABLE IF NOT EXISTS factory_admin_changed (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "new_admin" VARCHAR(40),
     "previous_admin" VARCHAR(40),
     PRIMARY KEY(evt_tx_hash,evt_index)
@@ -12,6 +14,8 @@ CREATE TABLE IF NOT EXISTS factory_beacon_upgraded (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "beacon" VARCHAR(40),
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
@@ -20,7 +24,21 @@ CREATE TABLE IF NOT EXISTS factory_deployed_lender_group_contract (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "group_contract" VARCHAR(40),
+    "deployer" VARCHAR(40),
+    "initial_principal_amount" DECIMAL,
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
+    "market_id" DECIMAL,
+    "max_loan_duration" INT,
+    "interest_rate_lower_bound" INT,
+    "interest_rate_upper_bound" INT,
+    "liquidity_threshold_percent" INT,
+    "loan_to_value_percent" INT,
+    "uniswap_pool_fee" INT,
+    "twap_interval" INT,
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS factory_upgraded (
@@ -28,6 +46,8 @@ CREATE TABLE IF NOT EXISTS factory_upgraded (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "implementation" VARCHAR(40),
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
@@ -37,6 +57,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_borrower_accepted_funds (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "bid_id" DECIMAL,
     "borrower" VARCHAR(40),
@@ -44,6 +68,26 @@ CREATE TABLE IF NOT EXISTS lendergroup_borrower_accepted_funds (
     "interest_rate" INT,
     "loan_duration" INT,
     "principal_amount" DECIMAL,
+    "principal_amount_normalized" DECIMAL,
+    "collateral_amount_normalized" DECIMAL,
+    "market_id" DECIMAL,
+    "payment_cycle_amount" DECIMAL,
+    "payment_cycle" INT,
+    "apr" INT,
+    "payment_type" VARCHAR(16),
+    "payment_cycle_type" VARCHAR(16),
+    "bid_accepted_at" TIMESTAMP,
+    "loan_due_date" TIMESTAMP,
+    "first_payment_due_date" TIMESTAMP,
+    "default_duration" INT,
+    "bid_rpc_enrichment_status" VARCHAR(12),
+    "twap_interval" INT,
+    "sqrt_twap_x96" DECIMAL,
+    "average_weighted_price" DECIMAL,
+    "required_collateral_amount" DECIMAL,
+    "collateral_excess" DECIMAL,
+    "oracle_rpc_enrichment_status" VARCHAR(12),
+    "oracle_swapped_after_loan" BOOLEAN,
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS lendergroup_defaulted_loan_liquidated (
@@ -51,11 +95,17 @@ CREATE TABLE IF NOT EXISTS lendergroup_defaulted_loan_liquidated (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "amount_due" DECIMAL,
     "bid_id" DECIMAL,
     "liquidator" VARCHAR(40),
     "token_amount_difference" DECIMAL,
+    "amount_due_normalized" DECIMAL,
+    "token_amount_difference_normalized" DECIMAL,
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS lendergroup_earnings_withdrawn (
@@ -63,11 +113,17 @@ CREATE TABLE IF NOT EXISTS lendergroup_earnings_withdrawn (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "amount_pool_shares_tokens" DECIMAL,
     "lender" VARCHAR(40),
     "principal_tokens_withdrawn" DECIMAL,
     "recipient" VARCHAR(40),
+    "amount_pool_shares_tokens_normalized" DECIMAL,
+    "principal_tokens_withdrawn_normalized" DECIMAL,
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS lendergroup_initialized (
@@ -75,6 +131,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_initialized (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "version" INT,
     PRIMARY KEY(evt_tx_hash,evt_index)
@@ -84,11 +144,17 @@ CREATE TABLE IF NOT EXISTS lendergroup_lender_added_principal (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "amount" DECIMAL,
     "lender" VARCHAR(40),
     "shares_amount" DECIMAL,
     "shares_recipient" VARCHAR(40),
+    "amount_normalized" DECIMAL,
+    "shares_amount_normalized" DECIMAL,
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS lendergroup_loan_repaid (
@@ -96,6 +162,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_loan_repaid (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "bid_id" DECIMAL,
     "interest_amount" DECIMAL,
@@ -103,6 +173,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_loan_repaid (
     "repayer" VARCHAR(40),
     "total_interest_collected" DECIMAL,
     "total_principal_repaid" DECIMAL,
+    "principal_amount_normalized" DECIMAL,
+    "interest_amount_normalized" DECIMAL,
+    "total_principal_repaid_normalized" DECIMAL,
+    "total_interest_collected_normalized" DECIMAL,
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS lendergroup_ownership_transferred (
@@ -110,6 +184,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_ownership_transferred (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "new_owner" VARCHAR(40),
     "previous_owner" VARCHAR(40),
@@ -120,6 +198,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_paused (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "account" VARCHAR(40),
     PRIMARY KEY(evt_tx_hash,evt_index)
@@ -129,6 +211,8 @@ CREATE TABLE IF NOT EXISTS lendergroup_pool_initialized (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
     "evt_address" VARCHAR(40),
     "collateral_token_address" VARCHAR(40),
     "interest_rate_lower_bound" INT,
@@ -141,6 +225,10 @@ CREATE TABLE IF NOT EXISTS lendergroup_pool_initialized (
     "principal_token_address" VARCHAR(40),
     "twap_interval" INT,
     "uniswap_pool_fee" INT,
+    "rpc_enrichment_status" VARCHAR(12),
+    "uniswap_v3_pool_address" VARCHAR(40),
+    "teller_v2_address" VARCHAR(40),
+    "smart_commitment_forwarder_address" VARCHAR(40),
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
 CREATE TABLE IF NOT EXISTS lendergroup_unpaused (
@@ -148,7 +236,456 @@ CREATE TABLE IF NOT EXISTS lendergroup_unpaused (
     "evt_index" INT,
     "evt_block_time" TIMESTAMP,
     "evt_block_number" DECIMAL,
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
     "evt_address" VARCHAR(40),
     "account" VARCHAR(40),
     PRIMARY KEY(evt_tx_hash,evt_index)
 );
+
+CREATE TABLE IF NOT EXISTS group_pool_metrics (
+    "group_pool_address" VARCHAR(40),
+    "principal_token_address" VARCHAR(40),
+    "collateral_token_address" VARCHAR(40),
+    "shares_token_address" VARCHAR(40),
+    "uniswap_v3_pool_address" VARCHAR(40),
+    "teller_v2_address" VARCHAR(40),
+    "smart_commitment_forwarder_address" VARCHAR(40),
+    "rpc_enrichment_status" VARCHAR(12),
+    "market_id" DECIMAL,
+    "uniswap_pool_fee" INT,
+    "max_loan_duration" INT,
+    "twap_interval" INT,
+    "interest_rate_upper_bound" INT,
+    "interest_rate_lower_bound" INT,
+    "liquidity_threshold_percent" INT,
+    "collateral_ratio" INT,
+    "total_principal_tokens_committed" DECIMAL,
+    "total_principal_tokens_withdrawn" DECIMAL,
+    "total_principal_tokens_lended" DECIMAL,
+    "total_principal_tokens_repaid" DECIMAL,
+    "total_interest_collected" DECIMAL,
+    "token_difference_from_liquidations" DECIMAL,
+    PRIMARY KEY(group_pool_address)
+);
+
+CREATE TABLE IF NOT EXISTS group_lender_metrics (
+    "group_pool_address" VARCHAR(40),
+    "user_lender_address" VARCHAR(40),
+    "total_principal_tokens_committed" DECIMAL,
+    "total_principal_tokens_withdrawn" DECIMAL,
+    "shares_tokens_net" DECIMAL,
+    PRIMARY KEY(group_pool_address,user_lender_address)
+);
+
+CREATE TABLE IF NOT EXISTS group_pool_metrics_data_point (
+    "group_pool_address" VARCHAR(40),
+    "interval" VARCHAR(8),
+    "period_start" DECIMAL,
+    "block_number" DECIMAL,
+    "block_time" TIMESTAMP,
+    "total_principal_tokens_committed" DECIMAL,
+    "total_principal_tokens_withdrawn" DECIMAL,
+    "total_principal_tokens_lended" DECIMAL,
+    "total_principal_tokens_repaid" DECIMAL,
+    "total_interest_collected" DECIMAL,
+    "token_difference_from_liquidations" DECIMAL,
+    "total_principal_tokens_outstanding" DECIMAL,
+    "pool_total_estimated_value" DECIMAL,
+    "utilization_ratio" DECIMAL,
+    PRIMARY KEY("group_pool_address","interval","period_start")
+);
+
+CREATE TABLE IF NOT EXISTS loan (
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "status" VARCHAR(16),
+    "borrower" VARCHAR(40),
+    "principal_amount" DECIMAL,
+    "collateral_amount" DECIMAL,
+    "loan_duration" INT,
+    "interest_rate" INT,
+    "accepted_tx_hash" VARCHAR(64),
+    "accepted_block_number" DECIMAL,
+    "accepted_at" TIMESTAMP,
+    "principal_repaid" DECIMAL,
+    "interest_repaid" DECIMAL,
+    "principal_amount_normalized" DECIMAL,
+    "collateral_amount_normalized" DECIMAL,
+    "principal_repaid_normalized" DECIMAL,
+    "interest_repaid_normalized" DECIMAL,
+    "last_repaid_at" TIMESTAMP,
+    "liquidated_at" TIMESTAMP,
+    "liquidator" VARCHAR(40),
+    "liquidation_amount_due" DECIMAL,
+    "liquidation_token_amount_difference" DECIMAL,
+    "liquidation_amount_due_normalized" DECIMAL,
+    "liquidation_token_amount_difference_normalized" DECIMAL,
+    "updated_block_number" DECIMAL,
+    "updated_at" TIMESTAMP,
+    "market_id" DECIMAL,
+    "payment_cycle_amount" DECIMAL,
+    "payment_cycle" INT,
+    "apr" INT,
+    "payment_type" VARCHAR(16),
+    "payment_cycle_type" VARCHAR(16),
+    "bid_accepted_at" TIMESTAMP,
+    "loan_due_date" TIMESTAMP,
+    "first_payment_due_date" TIMESTAMP,
+    "default_duration" INT,
+    "bid_rpc_enrichment_status" VARCHAR(12),
+    PRIMARY KEY(group_pool_address,bid_id)
+);
+
+CREATE TABLE IF NOT EXISTS token (
+    "address" VARCHAR(40),
+    "name" VARCHAR(256),
+    "symbol" VARCHAR(64),
+    "decimals" INT,
+    "rpc_enrichment_status" VARCHAR(12),
+    "block_number" DECIMAL,
+    PRIMARY KEY(address)
+);
+
+CREATE TABLE IF NOT EXISTS share_transfer (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "from" VARCHAR(40),
+    "to" VARCHAR(40),
+    "value" DECIMAL,
+    "value_normalized" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS share_holder (
+    "group_pool_address" VARCHAR(40),
+    "holder_address" VARCHAR(40),
+    "balance" DECIMAL,
+    PRIMARY KEY(group_pool_address,holder_address)
+);
+
+CREATE TABLE IF NOT EXISTS share_exchange_rate (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "group_pool_address" VARCHAR(40),
+    "source" VARCHAR(32),
+    "principal_amount" DECIMAL,
+    "shares_amount" DECIMAL,
+    "exchange_rate" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS share_exchange_rate_candle (
+    "group_pool_address" VARCHAR(40),
+    "period_start" DECIMAL,
+    "open" DECIMAL,
+    "high" DECIMAL,
+    "low" DECIMAL,
+    "close" DECIMAL,
+    "block_number" DECIMAL,
+    "block_time" TIMESTAMP,
+    PRIMARY KEY(group_pool_address,period_start)
+);
+
+CREATE TABLE IF NOT EXISTS pool_onchain_snapshot (
+    "group_pool_address" VARCHAR(40),
+    "block_number" DECIMAL,
+    "block_time" TIMESTAMP,
+    "pool_utilization_ratio" INT,
+    "shares_exchange_rate" DECIMAL,
+    "principal_amount_available_to_borrow" DECIMAL,
+    "total_principal_tokens_committed" DECIMAL,
+    "total_principal_tokens_outstanding_in_active_loans" DECIMAL,
+    "total_principal_tokens_lended" DECIMAL,
+    "total_principal_tokens_repaid" DECIMAL,
+    "total_interest_collected" DECIMAL,
+    "total_collateral_tokens_escrowed_for_loans" DECIMAL,
+    "paused" BOOLEAN,
+    "rpc_enrichment_status" VARCHAR(12),
+    PRIMARY KEY(group_pool_address,block_number)
+);
+
+CREATE TABLE IF NOT EXISTS reconciliation_mismatch (
+    "group_pool_address" VARCHAR(40),
+    "block_number" DECIMAL,
+    "block_time" TIMESTAMP,
+    "total" VARCHAR(40),
+    "event_derived_value" DECIMAL,
+    "onchain_value" DECIMAL,
+    "difference" DECIMAL,
+    PRIMARY KEY(group_pool_address,block_number,total)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_add_principal_to_commitment_group (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "amount" DECIMAL,
+    "shares_recipient" VARCHAR(40),
+    "output_shares_amount" DECIMAL,
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_burn_shares_to_withdraw_earnings (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "amount_pool_shares_tokens" DECIMAL,
+    "recipient" VARCHAR(40),
+    "output_principal_tokens_withdrawn" DECIMAL,
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_liquidate_defaulted_loan_with_incentive (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "bid_id" DECIMAL,
+    "token_amount_difference" DECIMAL,
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_pause_borrowing (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_unpause_borrowing (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_accept_funds_for_accept_bid (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "borrower" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "principal_amount" DECIMAL,
+    "collateral_amount" DECIMAL,
+    "collateral_token_address" VARCHAR(40),
+    "collateral_token_id" DECIMAL,
+    "loan_duration" INT,
+    "interest_rate" INT,
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS lendergroup_call_repay_loan_callback (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_success" BOOLEAN,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "bid_id" DECIMAL,
+    "repayer" VARCHAR(40),
+    "principal_amount" DECIMAL,
+    "interest_amount" DECIMAL,
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS reverted_call (
+    "call_tx_hash" VARCHAR(64),
+    "call_ordinal" DECIMAL,
+    "call_block_time" TIMESTAMP,
+    "call_block_number" DECIMAL,
+    "call_address" VARCHAR(40),
+    "caller" VARCHAR(40),
+    "tx_from" VARCHAR(40),
+    "factory_address" VARCHAR(40),
+    "factory_version" VARCHAR(16),
+    "function" VARCHAR(64),
+    "arguments" TEXT,
+    "gas_used" DECIMAL,
+    "revert_reason" TEXT,
+    "revert_data" TEXT,
+    "failure_reason" TEXT,
+    PRIMARY KEY(call_tx_hash,call_ordinal)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_submitted_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "borrower" VARCHAR(40),
+    "receiver" VARCHAR(40),
+    "metadata_uri" VARCHAR(64),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_accepted_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "lender" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_cancelled_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_market_owner_cancelled_bid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_repayment (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_repaid (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_liquidated (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "liquidator" VARCHAR(40),
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS teller_v2_loan_closed (
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "evt_block_time" TIMESTAMP,
+    "evt_block_number" DECIMAL,
+    "evt_address" VARCHAR(40),
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    PRIMARY KEY(evt_tx_hash,evt_index)
+);
+
+CREATE TABLE IF NOT EXISTS collateral_price (
+    "group_pool_address" VARCHAR(40),
+    "evt_block_number" DECIMAL,
+    "evt_block_time" TIMESTAMP,
+    "evt_tx_hash" VARCHAR(64),
+    "evt_index" INT,
+    "uniswap_v3_pool_address" VARCHAR(40),
+    "sqrt_price_x96" DECIMAL,
+    "tick" INT,
+    "price" DECIMAL,
+    "price_normalized" DECIMAL,
+    PRIMARY KEY(group_pool_address,evt_block_number)
+);
+
+CREATE TABLE IF NOT EXISTS loan_health (
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "block_number" DECIMAL,
+    "block_time" TIMESTAMP,
+    "status" VARCHAR(16),
+    "outstanding_principal" DECIMAL,
+    "collateral_amount" DECIMAL,
+    "collateral_value" DECIMAL,
+    "ltv_percent" DECIMAL,
+    "price_block_number" DECIMAL,
+    "collateral_ratio" INT,
+    "max_ltv_percent" DECIMAL,
+    "above_max_ltv" BOOLEAN,
+    "warning_level" INT,
+    PRIMARY KEY(group_pool_address,bid_id,block_number)
+);
+
+CREATE TABLE IF NOT EXISTS loan_health_alert (
+    "group_pool_address" VARCHAR(40),
+    "bid_id" DECIMAL,
+    "block_number" DECIMAL,
+    "block_time" TIMESTAMP,
+    "previous_warning_level" INT,
+    "warning_level" INT,
+    "ltv_percent" DECIMAL,
+    PRIMARY KEY(group_pool_address,bid_id,block_number)
+);
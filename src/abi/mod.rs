@@ -1,3 +1,6 @@
-
+pub mod erc20;
+pub mod erc20_bytes32;
 pub mod factory_contract;
-pub mod lendergroup_contract;
\ No newline at end of file
+pub mod lendergroup_contract;
+pub mod teller_v2;
+pub mod uniswap_v3_pool;
\ No newline at end of file
@@ -0,0 +1,33 @@
+use std::str::FromStr;
+use substreams::scalar::BigDecimal;
+
+// raw base unit amount scaled down by the token decimals, None when the decimals are unknown
+pub fn normalized_amount(raw: &str, decimals: Option<u64>) -> Option<String> {
+    let decimals = decimals?;
+    BigDecimal::from_str(&format!("{}e-{}", raw, decimals))
+        .ok()
+        .map(|amount| amount.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn scales_by_the_token_decimals() {
+        assert_eq!(decimal(&normalized_amount("1500000", Some(6)).unwrap()), decimal("1.5"));
+        assert_eq!(decimal(&normalized_amount("1", Some(18)).unwrap()), decimal("0.000000000000000001"));
+        assert_eq!(decimal(&normalized_amount("-250", Some(2)).unwrap()), decimal("-2.5"));
+        assert_eq!(decimal(&normalized_amount("42", Some(0)).unwrap()), decimal("42"));
+    }
+
+    #[test]
+    fn unset_without_decimals_or_amount() {
+        assert_eq!(normalized_amount("1500000", None), None);
+        assert_eq!(normalized_amount("", Some(6)), None);
+    }
+}
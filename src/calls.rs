@@ -0,0 +1,293 @@
+use crate::abi::lendergroup_contract::functions;
+use crate::factories;
+use crate::pb::contract::v1 as contract;
+use std::str::FromStr;
+use substreams::scalar::BigDecimal;
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Function;
+
+// a group pool function call, decoded once for map_calls and the reverted call rows
+pub enum LendergroupCall {
+    AddPrincipalToCommitmentGroup(functions::AddPrincipalToCommitmentGroup),
+    BurnSharesToWithdrawEarnings(functions::BurnSharesToWithdrawEarnings),
+    LiquidateDefaultedLoanWithIncentive(functions::LiquidateDefaultedLoanWithIncentive),
+    PauseBorrowing,
+    UnpauseBorrowing,
+    // boxed, its eight arguments dwarf the other variants
+    AcceptFundsForAcceptBid(Box<functions::AcceptFundsForAcceptBid>),
+    RepayLoanCallback(functions::RepayLoanCallback),
+}
+
+impl LendergroupCall {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LendergroupCall::AddPrincipalToCommitmentGroup(_) => functions::AddPrincipalToCommitmentGroup::NAME,
+            LendergroupCall::BurnSharesToWithdrawEarnings(_) => functions::BurnSharesToWithdrawEarnings::NAME,
+            LendergroupCall::LiquidateDefaultedLoanWithIncentive(_) => functions::LiquidateDefaultedLoanWithIncentive::NAME,
+            LendergroupCall::PauseBorrowing => functions::PauseBorrowing::NAME,
+            LendergroupCall::UnpauseBorrowing => functions::UnpauseBorrowing::NAME,
+            LendergroupCall::AcceptFundsForAcceptBid(_) => functions::AcceptFundsForAcceptBid::NAME,
+            LendergroupCall::RepayLoanCallback(_) => functions::RepayLoanCallback::NAME,
+        }
+    }
+}
+
+pub fn decode_lendergroup_call(call: &eth::Call) -> Option<LendergroupCall> {
+    if let Some(decoded) = functions::AddPrincipalToCommitmentGroup::match_and_decode(call) {
+        Some(LendergroupCall::AddPrincipalToCommitmentGroup(decoded))
+    } else if let Some(decoded) = functions::BurnSharesToWithdrawEarnings::match_and_decode(call) {
+        Some(LendergroupCall::BurnSharesToWithdrawEarnings(decoded))
+    } else if let Some(decoded) = functions::LiquidateDefaultedLoanWithIncentive::match_and_decode(call) {
+        Some(LendergroupCall::LiquidateDefaultedLoanWithIncentive(decoded))
+    } else if functions::PauseBorrowing::match_call(call) {
+        Some(LendergroupCall::PauseBorrowing)
+    } else if functions::UnpauseBorrowing::match_call(call) {
+        Some(LendergroupCall::UnpauseBorrowing)
+    } else if let Some(decoded) = functions::AcceptFundsForAcceptBid::match_and_decode(call) {
+        Some(LendergroupCall::AcceptFundsForAcceptBid(Box::new(decoded)))
+    } else {
+        functions::RepayLoanCallback::match_and_decode(call).map(LendergroupCall::RepayLoanCallback)
+    }
+}
+
+// one decoded call trace to a tracked pool, reverted calls are kept with call_success = false
+pub fn map_lendergroup_v1_call(
+    blk: &eth::Block,
+    trx: &eth::TransactionTrace,
+    call: &eth::Call,
+    decoded: LendergroupCall,
+    factory: contract::TrackedFactory,
+    calls: &mut contract::Calls,
+) {
+    let call_tx_hash = Hex(&trx.hash).to_string();
+    let call_success = !call.state_reverted;
+    let call_address = Hex(&call.address).to_string();
+
+    match decoded {
+        LendergroupCall::AddPrincipalToCommitmentGroup(decoded) => {
+            calls.lendergroup_call_add_principal_to_commitment_groups.push(contract::LendergroupAddPrincipalToCommitmentGroupCall {
+                call_tx_hash,
+                call_block_time: blk.timestamp_seconds(),
+                call_block_number: blk.number,
+                call_ordinal: call.begin_ordinal,
+                call_success,
+                call_address,
+                caller: call.caller.clone(),
+                factory_address: factory.address,
+                factory_version: factory.version,
+                amount: decoded.u_amount.to_string(),
+                shares_recipient: decoded.u_shares_recipient,
+                output_shares_amount: functions::AddPrincipalToCommitmentGroup::output_call(call)
+                    .ok()
+                    .filter(|_| call_success)
+                    .map(|shares_amount| shares_amount.to_string()),
+            });
+        }
+        LendergroupCall::BurnSharesToWithdrawEarnings(decoded) => {
+            calls.lendergroup_call_burn_shares_to_withdraw_earnings.push(contract::LendergroupBurnSharesToWithdrawEarningsCall {
+                call_tx_hash,
+                call_block_time: blk.timestamp_seconds(),
+                call_block_number: blk.number,
+                call_ordinal: call.begin_ordinal,
+                call_success,
+                call_address,
+                caller: call.caller.clone(),
+                factory_address: factory.address,
+                factory_version: factory.version,
+                amount_pool_shares_tokens: decoded.u_amount_pool_shares_tokens.to_string(),
+                recipient: decoded.u_recipient,
+                output_principal_tokens_withdrawn: functions::BurnSharesToWithdrawEarnings::output_call(call)
+                    .ok()
+                    .filter(|_| call_success)
+                    .map(|principal_tokens_withdrawn| principal_tokens_withdrawn.to_string()),
+            });
+        }
+        LendergroupCall::LiquidateDefaultedLoanWithIncentive(decoded) => {
+            calls.lendergroup_call_liquidate_defaulted_loan_with_incentives.push(
+                contract::LendergroupLiquidateDefaultedLoanWithIncentiveCall {
+                    call_tx_hash,
+                    call_block_time: blk.timestamp_seconds(),
+                    call_block_number: blk.number,
+                    call_ordinal: call.begin_ordinal,
+                    call_success,
+                    call_address,
+                    caller: call.caller.clone(),
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    bid_id: decoded.u_bid_id.to_string(),
+                    token_amount_difference: decoded.u_token_amount_difference.to_string(),
+                },
+            );
+        }
+        LendergroupCall::PauseBorrowing => {
+            calls.lendergroup_call_pause_borrowings.push(contract::LendergroupPauseBorrowingCall {
+                call_tx_hash,
+                call_block_time: blk.timestamp_seconds(),
+                call_block_number: blk.number,
+                call_ordinal: call.begin_ordinal,
+                call_success,
+                call_address,
+                caller: call.caller.clone(),
+                factory_address: factory.address,
+                factory_version: factory.version,
+            });
+        }
+        LendergroupCall::UnpauseBorrowing => {
+            calls.lendergroup_call_unpause_borrowings.push(contract::LendergroupUnpauseBorrowingCall {
+                call_tx_hash,
+                call_block_time: blk.timestamp_seconds(),
+                call_block_number: blk.number,
+                call_ordinal: call.begin_ordinal,
+                call_success,
+                call_address,
+                caller: call.caller.clone(),
+                factory_address: factory.address,
+                factory_version: factory.version,
+            });
+        }
+        LendergroupCall::AcceptFundsForAcceptBid(decoded) => {
+            calls.lendergroup_call_accept_funds_for_accept_bids.push(contract::LendergroupAcceptFundsForAcceptBidCall {
+                call_tx_hash,
+                call_block_time: blk.timestamp_seconds(),
+                call_block_number: blk.number,
+                call_ordinal: call.begin_ordinal,
+                call_success,
+                call_address,
+                caller: call.caller.clone(),
+                factory_address: factory.address,
+                factory_version: factory.version,
+                borrower: decoded.u_borrower,
+                bid_id: decoded.u_bid_id.to_string(),
+                principal_amount: decoded.u_principal_amount.to_string(),
+                collateral_amount: decoded.u_collateral_amount.to_string(),
+                collateral_token_address: decoded.u_collateral_token_address,
+                collateral_token_id: decoded.u_collateral_token_id.to_string(),
+                loan_duration: decoded.u_loan_duration.to_u64(),
+                interest_rate: decoded.u_interest_rate.to_u64(),
+            });
+        }
+        LendergroupCall::RepayLoanCallback(decoded) => {
+            calls.lendergroup_call_repay_loan_callbacks.push(contract::LendergroupRepayLoanCallbackCall {
+                call_tx_hash,
+                call_block_time: blk.timestamp_seconds(),
+                call_block_number: blk.number,
+                call_ordinal: call.begin_ordinal,
+                call_success,
+                call_address,
+                caller: call.caller.clone(),
+                factory_address: factory.address,
+                factory_version: factory.version,
+                bid_id: decoded.u_bid_id.to_string(),
+                repayer: decoded.repayer,
+                principal_amount: decoded.principal_amount.to_string(),
+                interest_amount: decoded.interest_amount.to_string(),
+            });
+        }
+    }
+}
+
+pub fn db_calls_out(calls: &contract::Calls, tables: &mut DatabaseChangeTables) {
+    calls.lendergroup_call_add_principal_to_commitment_groups.iter().for_each(|call| {
+        let row = tables
+            .create_row("lendergroup_call_add_principal_to_commitment_group", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string())
+            .set("amount", BigDecimal::from_str(&call.amount).unwrap())
+            .set("shares_recipient", Hex(&call.shares_recipient).to_string());
+        if let Some(output_shares_amount) = &call.output_shares_amount {
+            row.set("output_shares_amount", BigDecimal::from_str(output_shares_amount).unwrap());
+        }
+    });
+    calls.lendergroup_call_burn_shares_to_withdraw_earnings.iter().for_each(|call| {
+        let row = tables
+            .create_row("lendergroup_call_burn_shares_to_withdraw_earnings", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string())
+            .set("amount_pool_shares_tokens", BigDecimal::from_str(&call.amount_pool_shares_tokens).unwrap())
+            .set("recipient", Hex(&call.recipient).to_string());
+        if let Some(output_principal_tokens_withdrawn) = &call.output_principal_tokens_withdrawn {
+            row.set("output_principal_tokens_withdrawn", BigDecimal::from_str(output_principal_tokens_withdrawn).unwrap());
+        }
+    });
+    calls.lendergroup_call_liquidate_defaulted_loan_with_incentives.iter().for_each(|call| {
+        tables
+            .create_row("lendergroup_call_liquidate_defaulted_loan_with_incentive", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string())
+            .set("bid_id", BigDecimal::from_str(&call.bid_id).unwrap())
+            .set("token_amount_difference", BigDecimal::from_str(&call.token_amount_difference).unwrap());
+    });
+    calls.lendergroup_call_pause_borrowings.iter().for_each(|call| {
+        tables
+            .create_row("lendergroup_call_pause_borrowing", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string());
+    });
+    calls.lendergroup_call_unpause_borrowings.iter().for_each(|call| {
+        tables
+            .create_row("lendergroup_call_unpause_borrowing", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string());
+    });
+    calls.lendergroup_call_accept_funds_for_accept_bids.iter().for_each(|call| {
+        tables
+            .create_row("lendergroup_call_accept_funds_for_accept_bid", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string())
+            .set("borrower", Hex(&call.borrower).to_string())
+            .set("bid_id", BigDecimal::from_str(&call.bid_id).unwrap())
+            .set("principal_amount", BigDecimal::from_str(&call.principal_amount).unwrap())
+            .set("collateral_amount", BigDecimal::from_str(&call.collateral_amount).unwrap())
+            .set("collateral_token_address", Hex(&call.collateral_token_address).to_string())
+            .set("collateral_token_id", BigDecimal::from_str(&call.collateral_token_id).unwrap())
+            .set("loan_duration", call.loan_duration)
+            .set("interest_rate", call.interest_rate);
+    });
+    calls.lendergroup_call_repay_loan_callbacks.iter().for_each(|call| {
+        tables
+            .create_row("lendergroup_call_repay_loan_callback", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_success", call.call_success)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string())
+            .set("bid_id", BigDecimal::from_str(&call.bid_id).unwrap())
+            .set("repayer", Hex(&call.repayer).to_string())
+            .set("principal_amount", BigDecimal::from_str(&call.principal_amount).unwrap())
+            .set("interest_amount", BigDecimal::from_str(&call.interest_amount).unwrap());
+    });
+}
@@ -0,0 +1,129 @@
+use crate::pb::contract::v1 as contract;
+use crate::rpc;
+use crate::rpc_failures;
+use crate::topics;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{StoreGet, StoreGetProto};
+use substreams_database_change::tables::Row as DatabaseChangeRow;
+use substreams_entity_change::tables::Row as EntityChangesRow;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+fn collateral_audit(
+    collateral_amount: &str,
+    twap_interval: u64,
+    oracle_swapped_after_loan: bool,
+    fetched: rpc::LoanOracleDataFromRpc,
+) -> contract::CollateralAudit {
+    let collateral_excess = fetched
+        .required_collateral_amount
+        .as_ref()
+        .map(|required| (BigInt::from_str(collateral_amount).unwrap() - required.clone()).to_string());
+    contract::CollateralAudit {
+        rpc_enrichment_status: fetched.enrichment_status() as i32,
+        twap_interval: Some(twap_interval),
+        sqrt_twap_x96: fetched.sqrt_twap_x96.map(|sqrt_twap| sqrt_twap.to_string()),
+        average_weighted_price: fetched.average_weighted_price.map(|price| price.to_string()),
+        required_collateral_amount: fetched.required_collateral_amount.map(|required| required.to_string()),
+        collateral_excess,
+        oracle_swapped_after_loan,
+    }
+}
+
+// ordinal of the last Swap of every Uniswap V3 pool in the block
+fn last_swap_ordinals(blk: &eth::Block) -> BTreeMap<Vec<u8>, u64> {
+    let mut last_swaps = BTreeMap::new();
+    for rcpt in blk.receipts() {
+        for log in rcpt.receipt.logs.iter() {
+            if log.topics.first().map(|topic0| topic0.as_slice()) == Some(&topics::uniswap_v3_pool::SWAP[..]) {
+                last_swaps.insert(log.address.clone(), log.ordinal);
+            }
+        }
+    }
+    last_swaps
+}
+
+// attaches the end of block oracle price and required collateral to every loan accepted in the block
+pub fn enrich_collateral_audits(
+    blk: &eth::Block,
+    accepted_funds: &mut [contract::LendergroupBorrowerAcceptedFunds],
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+) {
+    // the TWAP interval is a pool parameter of PoolInitialized, already in the pool config
+    let loans: Vec<(usize, Vec<u8>, contract::PoolConfig)> = accepted_funds
+        .iter()
+        .enumerate()
+        .filter_map(|(i, evt)| {
+            let Ok(pool_address) = hex::decode(&evt.evt_address) else {
+                substreams::log::info!("Skipping collateral audit of bid {}, invalid group pool address {}", evt.bid_id, evt.evt_address);
+                return None;
+            };
+            let Some(pool_config) = pool_configs.get_last(&evt.evt_address) else {
+                substreams::log::info!("Skipping collateral audit of bid {}, no pool config for {}", evt.bid_id, evt.evt_address);
+                return None;
+            };
+            Some((i, pool_address, pool_config))
+        })
+        .collect();
+
+    let last_swaps = last_swap_ordinals(blk);
+    let calls: Vec<(Vec<u8>, BigInt, u64)> = loans
+        .iter()
+        .map(|(i, pool_address, pool_config)| {
+            let principal_amount = BigInt::from_str(&accepted_funds[*i].principal_amount).unwrap();
+            (pool_address.clone(), principal_amount, pool_config.twap_interval)
+        })
+        .collect();
+    let fetched = rpc::fetch_loan_oracle_data_from_rpc(&calls);
+
+    for ((i, _, pool_config), fetched_rpc_data) in loans.into_iter().zip(fetched) {
+        let evt = &mut accepted_funds[i];
+        let oracle_swapped_after_loan = pool_config
+            .uniswap_v3_pool_address
+            .and_then(|uniswap_v3_pool_address| last_swaps.get(&uniswap_v3_pool_address).copied())
+            .map_or(false, |last_swap_ordinal| last_swap_ordinal > evt.evt_ordinal);
+        evt.collateral_audit = Some(collateral_audit(
+            &evt.collateral_amount,
+            pool_config.twap_interval,
+            oracle_swapped_after_loan,
+            fetched_rpc_data,
+        ));
+    }
+}
+
+pub fn db_collateral_audit(audit: &contract::CollateralAudit, row: &mut DatabaseChangeRow) {
+    row.set("oracle_rpc_enrichment_status", rpc_failures::status_label(audit.rpc_enrichment_status()).to_string());
+    row.set("oracle_swapped_after_loan", audit.oracle_swapped_after_loan);
+    if let Some(twap_interval) = audit.twap_interval {
+        row.set("twap_interval", twap_interval);
+    }
+    for (column, amount) in [
+        ("sqrt_twap_x96", &audit.sqrt_twap_x96),
+        ("average_weighted_price", &audit.average_weighted_price),
+        ("required_collateral_amount", &audit.required_collateral_amount),
+        ("collateral_excess", &audit.collateral_excess),
+    ] {
+        if let Some(amount) = amount {
+            row.set(column, BigDecimal::from_str(amount).unwrap());
+        }
+    }
+}
+
+pub fn graph_collateral_audit(audit: &contract::CollateralAudit, row: &mut EntityChangesRow) {
+    row.set("oracle_rpc_enrichment_status", rpc_failures::status_label(audit.rpc_enrichment_status()).to_string());
+    row.set("oracle_swapped_after_loan", audit.oracle_swapped_after_loan);
+    if let Some(twap_interval) = audit.twap_interval {
+        row.set("twap_interval", twap_interval);
+    }
+    for (column, amount) in [
+        ("sqrt_twap_x96", &audit.sqrt_twap_x96),
+        ("average_weighted_price", &audit.average_weighted_price),
+        ("required_collateral_amount", &audit.required_collateral_amount),
+        ("collateral_excess", &audit.collateral_excess),
+    ] {
+        if let Some(amount) = amount {
+            row.set(column, BigDecimal::from_str(amount).unwrap());
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::abi::uniswap_v3_pool::events;
+use crate::pb::contract::v1 as contract;
+use crate::topics;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{StoreAppend, StoreGet, StoreGetArray, StoreGetProto, StoreSet, StoreSetProto};
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+// 2^192, sqrtPriceX96 is the square root of the token1 per token0 price as a Q64.96
+const Q192: &str = "6277101735386680763835789423207666416102355444464034512896";
+
+// store_uniswap_pools key of a Uniswap V3 oracle pool, the values are the group pools it prices
+pub fn uniswap_pool_key(uniswap_pool_address: &[u8]) -> String {
+    format!("uniswap_pool:{}", Hex(uniswap_pool_address))
+}
+
+pub fn append_uniswap_pools(events: &contract::Events, store: &StoreAppend<String>) {
+    events.lendergroup_pool_initializeds.iter().for_each(|evt| {
+        // the oracle pool becomes a data source of the group pool, its Swap logs are decoded by map_collateral_prices
+        if let Some(uniswap_v3_pool_address) = &evt.uniswap_v3_pool_address {
+            store.append(evt.evt_ordinal, uniswap_pool_key(uniswap_v3_pool_address), evt.evt_address.clone());
+        }
+    });
+}
+
+// Uniswap orders the pair by token address, so the principal token is token0 when its address is the lower one
+fn principal_is_token0(pool_config: &contract::PoolConfig) -> bool {
+    pool_config.principal_token_address < pool_config.collateral_token_address
+}
+
+// collateral base units per principal base unit
+fn collateral_per_principal(sqrt_price_x96: &BigInt, principal_is_token0: bool) -> Option<BigDecimal> {
+    if *sqrt_price_x96 == BigInt::zero() {
+        return None;
+    }
+    let price_x192 = (sqrt_price_x96.clone() * sqrt_price_x96.clone()).to_decimal(0);
+    let q192 = BigDecimal::from_str(Q192).unwrap();
+    if principal_is_token0 {
+        Some(price_x192 / q192)
+    } else {
+        Some(q192 / price_x192)
+    }
+}
+
+// collateral tokens per principal token, None when the decimals are unknown
+fn normalized_price(price: &BigDecimal, pool_config: &contract::PoolConfig) -> Option<String> {
+    let principal_decimals = pool_config.principal_token_decimals? as i64;
+    let collateral_decimals = pool_config.collateral_token_decimals? as i64;
+    let scale = BigDecimal::from_str(&format!("1e{}", principal_decimals - collateral_decimals)).ok()?;
+    Some((price.clone() * scale).to_string())
+}
+
+pub fn map_collateral_prices(
+    blk: &eth::Block,
+    uniswap_pools: &StoreGetArray<String>,
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+) -> contract::CollateralPrices {
+    // the last Swap of the block wins for each group pool
+    let mut prices: BTreeMap<String, contract::CollateralPrice> = BTreeMap::new();
+
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(Ok(topic0)) = log.topics.first().map(|topic| <[u8; 32]>::try_from(topic.as_slice())) else {
+                continue;
+            };
+            if topic0 != topics::uniswap_v3_pool::SWAP {
+                continue;
+            }
+            let Some(group_pools) = uniswap_pools.get_last(uniswap_pool_key(&log.address)) else {
+                continue;
+            };
+            let Some(swap) = events::Swap::match_and_decode(log) else {
+                continue;
+            };
+            for group_pool_address in group_pools {
+                let Some(pool_config) = pool_configs.get_last(&group_pool_address) else {
+                    continue;
+                };
+                let Some(price) = collateral_per_principal(&swap.sqrt_price_x96, principal_is_token0(&pool_config)) else {
+                    continue;
+                };
+                prices.insert(
+                    group_pool_address.clone(),
+                    contract::CollateralPrice {
+                        group_pool_address,
+                        uniswap_v3_pool_address: Hex(&log.address).to_string(),
+                        evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        sqrt_price_x96: swap.sqrt_price_x96.to_string(),
+                        tick: swap.tick.to_i32(),
+                        price_normalized: normalized_price(&price, &pool_config),
+                        price: price.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    contract::CollateralPrices {
+        prices: prices.into_values().collect(),
+    }
+}
+
+pub fn set_latest_collateral_prices(prices: &contract::CollateralPrices, store: &StoreSetProto<contract::CollateralPrice>) {
+    prices.prices.iter().for_each(|price| {
+        store.set(price.evt_ordinal, price.group_pool_address.clone(), price);
+    });
+}
+
+pub fn db_collateral_prices_out(prices: &contract::CollateralPrices, tables: &mut DatabaseChangeTables) {
+    prices.prices.iter().for_each(|price| {
+        let row = tables
+            .create_row(
+                "collateral_price",
+                [
+                    ("group_pool_address", price.group_pool_address.to_string()),
+                    ("evt_block_number", price.evt_block_number.to_string()),
+                ],
+            )
+            .set("evt_block_time", price.evt_block_time)
+            .set("evt_tx_hash", &price.evt_tx_hash)
+            .set("evt_index", price.evt_index)
+            .set("uniswap_v3_pool_address", &price.uniswap_v3_pool_address)
+            .set("sqrt_price_x96", BigDecimal::from_str(&price.sqrt_price_x96).unwrap())
+            .set("tick", price.tick)
+            .set("price", BigDecimal::from_str(&price.price).unwrap());
+        if let Some(price_normalized) = &price.price_normalized {
+            row.set("price_normalized", BigDecimal::from_str(price_normalized).unwrap());
+        }
+    });
+}
+
+pub fn graph_collateral_prices_out(prices: &contract::CollateralPrices, tables: &mut EntityChangesTables) {
+    prices.prices.iter().for_each(|price| {
+        let row = tables
+            .create_row("collateral_price", format!("{}-{}", price.group_pool_address, price.evt_block_number))
+            .set("group_pool_address", &price.group_pool_address)
+            .set("evt_block_number", BigInt::from(price.evt_block_number))
+            .set("evt_block_time", BigInt::from(price.evt_block_time))
+            .set("evt_tx_hash", &price.evt_tx_hash)
+            .set("evt_index", price.evt_index)
+            .set("uniswap_v3_pool_address", &price.uniswap_v3_pool_address)
+            .set("sqrt_price_x96", BigInt::from_str(&price.sqrt_price_x96).unwrap())
+            .set("tick", price.tick)
+            .set("price", BigDecimal::from_str(&price.price).unwrap());
+        if let Some(price_normalized) = &price.price_normalized {
+            row.set("price_normalized", BigDecimal::from_str(price_normalized).unwrap());
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2^96 is a price of 1, 2^97 a price of 4 token1 per token0
+    const SQRT_PRICE_ONE: &str = "79228162514264337593543950336";
+    const SQRT_PRICE_FOUR: &str = "158456325028528675187087900672";
+
+    fn price(sqrt_price_x96: &str, principal_is_token0: bool) -> Option<BigDecimal> {
+        collateral_per_principal(&BigInt::from_str(sqrt_price_x96).unwrap(), principal_is_token0)
+    }
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn no_price_at_zero_sqrt_price() {
+        assert_eq!(price("0", true), None);
+        assert_eq!(price("0", false), None);
+    }
+
+    #[test]
+    fn principal_token0_reads_the_pool_price() {
+        assert_eq!(price(SQRT_PRICE_ONE, true), Some(decimal("1")));
+        assert_eq!(price(SQRT_PRICE_FOUR, true), Some(decimal("4")));
+    }
+
+    #[test]
+    fn principal_token1_inverts_the_pool_price() {
+        assert_eq!(price(SQRT_PRICE_ONE, false), Some(decimal("1")));
+        assert_eq!(price(SQRT_PRICE_FOUR, false), Some(decimal("0.25")));
+    }
+
+    #[test]
+    fn principal_sorting_above_collateral_is_token1() {
+        let pool_config = contract::PoolConfig {
+            principal_token_address: vec![0x20; 20],
+            collateral_token_address: vec![0x10; 20],
+            ..Default::default()
+        };
+        assert!(!principal_is_token0(&pool_config));
+        assert!(principal_is_token0(&contract::PoolConfig {
+            principal_token_address: vec![0x10; 20],
+            collateral_token_address: vec![0x20; 20],
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn normalized_price_needs_both_decimals() {
+        let mut pool_config = contract::PoolConfig {
+            principal_token_decimals: Some(6),
+            collateral_token_decimals: Some(18),
+            ..Default::default()
+        };
+        // 6 decimals principal, 18 decimals collateral: 1e12 base units per base unit is 1 token per token
+        assert_eq!(decimal(&normalized_price(&decimal("1000000000000"), &pool_config).unwrap()), decimal("1"));
+        pool_config.collateral_token_decimals = None;
+        assert_eq!(normalized_price(&decimal("1000000000000"), &pool_config), None);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::pb::contract::v1 as contract;
+use crate::pool_metrics;
+use contract::DataPointInterval;
+use std::collections::{BTreeSet, HashSet};
+use std::str::FromStr;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{DeltaInt64, Deltas, StoreGet, StoreGetBigInt, StoreSet, StoreSetInt64};
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+const HOUR_IN_SECONDS: u64 = 3600;
+pub const DAY_IN_SECONDS: u64 = 86400;
+
+pub fn interval_label(interval: DataPointInterval) -> &'static str {
+    match interval {
+        DataPointInterval::Block => "block",
+        DataPointInterval::Hour => "hour",
+        DataPointInterval::Day => "day",
+        DataPointInterval::Unspecified => "unspecified",
+    }
+}
+
+// pools whose totals changed in this block (same events as pool_metrics::add_pool_totals)
+fn touched_pools(events: &contract::Events) -> BTreeSet<String> {
+    let mut pools = BTreeSet::new();
+    pools.extend(events.lendergroup_lender_added_principals.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_earnings_withdrawns.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_borrower_accepted_funds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_loan_repaids.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_defaulted_loan_liquidateds.iter().map(|evt| evt.evt_address.clone()));
+    pools
+}
+
+pub fn map_pool_metrics_data_points(
+    block_number: u64,
+    block_time: u64,
+    events: &contract::Events,
+    pool_totals: &StoreGetBigInt,
+) -> contract::PoolMetricsDataPoints {
+    let mut data_points = contract::PoolMetricsDataPoints::default();
+
+    for pool_address in touched_pools(events) {
+        let total = |name: &str| {
+            pool_totals
+                .get_last(pool_metrics::pool_total_key(&pool_address, name))
+                .unwrap_or_else(BigInt::zero)
+        };
+
+        let committed = total(pool_metrics::TOTAL_PRINCIPAL_TOKENS_COMMITTED);
+        let withdrawn = total(pool_metrics::TOTAL_PRINCIPAL_TOKENS_WITHDRAWN);
+        let lended = total(pool_metrics::TOTAL_PRINCIPAL_TOKENS_LENDED);
+        let repaid = total(pool_metrics::TOTAL_PRINCIPAL_TOKENS_REPAID);
+        let interest_collected = total(pool_metrics::TOTAL_INTEREST_COLLECTED);
+        let token_difference_from_liquidations = total(pool_metrics::TOKEN_DIFFERENCE_FROM_LIQUIDATIONS);
+
+        // same accounting as the contract's getPoolTotalEstimatedValue / getPoolUtilizationRatio
+        let outstanding = lended.clone() - repaid.clone();
+        let estimated_value = committed.clone() + interest_collected.clone()
+            + token_difference_from_liquidations.clone()
+            - withdrawn.clone();
+        let utilization_ratio = if estimated_value > BigInt::zero() {
+            outstanding.to_decimal(0) / estimated_value.to_decimal(0)
+        } else {
+            BigDecimal::zero()
+        };
+
+        for (interval, period_start) in [
+            (DataPointInterval::Block, block_time),
+            (DataPointInterval::Hour, block_time - block_time % HOUR_IN_SECONDS),
+            (DataPointInterval::Day, block_time - block_time % DAY_IN_SECONDS),
+        ] {
+            data_points.data_points.push(contract::PoolMetricsDataPoint {
+                group_pool_address: pool_address.clone(),
+                interval: interval as i32,
+                period_start,
+                block_number,
+                block_time,
+                total_principal_tokens_committed: committed.to_string(),
+                total_principal_tokens_withdrawn: withdrawn.to_string(),
+                total_principal_tokens_lended: lended.to_string(),
+                total_principal_tokens_repaid: repaid.to_string(),
+                total_interest_collected: interest_collected.to_string(),
+                token_difference_from_liquidations: token_difference_from_liquidations.to_string(),
+                total_principal_tokens_outstanding: outstanding.to_string(),
+                pool_total_estimated_value: estimated_value.to_string(),
+                utilization_ratio: utilization_ratio.to_string(),
+            });
+        }
+    }
+
+    data_points
+}
+
+// period:{group pool address}:{interval}, the value is the start of the pool's latest data point of the interval
+pub fn data_point_period_key(pool_address: &str, interval: DataPointInterval) -> String {
+    format!("period:{}:{}", pool_address, interval_label(interval))
+}
+
+pub fn set_data_point_periods(data_points: &contract::PoolMetricsDataPoints, store: &StoreSetInt64) {
+    data_points.data_points.iter().for_each(|point| {
+        store.set(
+            0,
+            data_point_period_key(&point.group_pool_address, point.interval()),
+            &(point.period_start as i64),
+        );
+    });
+}
+
+pub fn db_data_points_out(data_points: &contract::PoolMetricsDataPoints, tables: &mut DatabaseChangeTables) {
+    // hour / day rollups are upserted so the last block of the period wins
+    data_points.data_points.iter().for_each(|point| {
+        tables
+            .upsert_row(
+                "group_pool_metrics_data_point",
+                [
+                    ("group_pool_address", point.group_pool_address.to_string()),
+                    ("interval", interval_label(point.interval()).to_string()),
+                    ("period_start", point.period_start.to_string()),
+                ],
+            )
+            .set("block_number", point.block_number)
+            .set("block_time", point.block_time)
+            .set("total_principal_tokens_committed", BigDecimal::from_str(&point.total_principal_tokens_committed).unwrap())
+            .set("total_principal_tokens_withdrawn", BigDecimal::from_str(&point.total_principal_tokens_withdrawn).unwrap())
+            .set("total_principal_tokens_lended", BigDecimal::from_str(&point.total_principal_tokens_lended).unwrap())
+            .set("total_principal_tokens_repaid", BigDecimal::from_str(&point.total_principal_tokens_repaid).unwrap())
+            .set("total_interest_collected", BigDecimal::from_str(&point.total_interest_collected).unwrap())
+            .set("token_difference_from_liquidations", BigDecimal::from_str(&point.token_difference_from_liquidations).unwrap())
+            .set("total_principal_tokens_outstanding", BigDecimal::from_str(&point.total_principal_tokens_outstanding).unwrap())
+            .set("pool_total_estimated_value", BigDecimal::from_str(&point.pool_total_estimated_value).unwrap())
+            .set("utilization_ratio", BigDecimal::from_str(&point.utilization_ratio).unwrap());
+    });
+}
+
+pub fn graph_data_points_out(
+    data_points: &contract::PoolMetricsDataPoints,
+    data_point_periods_deltas: &Deltas<DeltaInt64>,
+    tables: &mut EntityChangesTables,
+) {
+    // a pool's data point of an interval is created when its period moves, hour / day rollups are then updated
+    // so the last block of the period wins
+    let new_periods: HashSet<&str> = data_point_periods_deltas
+        .deltas
+        .iter()
+        .filter(|delta| delta.operation == Operation::Create || delta.old_value != delta.new_value)
+        .map(|delta| delta.key.as_str())
+        .collect();
+    data_points.data_points.iter().for_each(|point| {
+        let interval = interval_label(point.interval());
+        let id = format!("{}-{}-{}", point.group_pool_address, interval, point.period_start);
+        let row = if new_periods.contains(data_point_period_key(&point.group_pool_address, point.interval()).as_str()) {
+            tables.create_row("group_pool_metrics_data_point", id)
+        } else {
+            tables.update_row("group_pool_metrics_data_point", id)
+        };
+        row.set("group_pool_address", &point.group_pool_address)
+            .set("interval", interval.to_string())
+            .set("period_start", BigInt::from(point.period_start))
+            .set("block_number", BigInt::from(point.block_number))
+            .set("block_time", BigInt::from(point.block_time))
+            .set("total_principal_tokens_committed", BigInt::from_str(&point.total_principal_tokens_committed).unwrap())
+            .set("total_principal_tokens_withdrawn", BigInt::from_str(&point.total_principal_tokens_withdrawn).unwrap())
+            .set("total_principal_tokens_lended", BigInt::from_str(&point.total_principal_tokens_lended).unwrap())
+            .set("total_principal_tokens_repaid", BigInt::from_str(&point.total_principal_tokens_repaid).unwrap())
+            .set("total_interest_collected", BigInt::from_str(&point.total_interest_collected).unwrap())
+            .set("token_difference_from_liquidations", BigInt::from_str(&point.token_difference_from_liquidations).unwrap())
+            .set("total_principal_tokens_outstanding", BigInt::from_str(&point.total_principal_tokens_outstanding).unwrap())
+            .set("pool_total_estimated_value", BigInt::from_str(&point.pool_total_estimated_value).unwrap())
+            .set("utilization_ratio", BigDecimal::from_str(&point.utilization_ratio).unwrap());
+    });
+}
@@ -0,0 +1,264 @@
+use crate::pb::contract::v1 as contract;
+use crate::{declared_dds_factory, factories, map_factory_v1_log, map_lendergroup_v1_log, map_v1_events, pool_configs};
+use crate::{dds_store_key, shares, topics, StoreLookup};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/*
+
+Synthetic block benchmark of the single pass topic0 dispatch of map_v1_events against the per event
+type loops it replaced (one walk over the block logs, with a pool store read on every log, for each
+of the 10 lender group events). Ignored by default, run it with
+
+  cargo test --release dispatch_bench -- --ignored --nocapture
+
+*/
+
+const POOLS: u8 = 50;
+const TRANSACTIONS: usize = 400;
+const LOGS_PER_TRANSACTION: usize = 8;
+const ITERATIONS: u32 = 20;
+
+// in-memory store counting its reads, a read is a wasm host call in the substreams runtime
+struct MemoryStore<T> {
+    values: BTreeMap<String, T>,
+    reads: Cell<usize>,
+}
+
+impl<T> MemoryStore<T> {
+    fn new(values: BTreeMap<String, T>) -> Self {
+        MemoryStore {
+            values,
+            reads: Cell::new(0),
+        }
+    }
+}
+
+impl<T: Clone> StoreLookup<T> for MemoryStore<T> {
+    fn lookup_at(&self, _ordinal: u64, key: &str) -> Option<T> {
+        self.reads.set(self.reads.get() + 1);
+        self.values.get(key).cloned()
+    }
+}
+
+fn factory_address() -> Vec<u8> {
+    vec![0xfa; 20]
+}
+
+fn pool_address(pool: u8) -> Vec<u8> {
+    let mut address = vec![0x10; 20];
+    address[19] = pool;
+    address
+}
+
+fn abi_address(address: &[u8]) -> Vec<u8> {
+    let mut word = vec![0u8; 12];
+    word.extend_from_slice(address);
+    word
+}
+
+// per transaction: a Paused and an Unpaused log of a tracked pool, ERC20 Transfers of untracked tokens
+// and logs of unrelated contracts, which most logs of a real block are
+fn synthetic_block() -> eth::Block {
+    let mut ordinal = 0;
+    let mut block_index = 0;
+    let transaction_traces = (0..TRANSACTIONS)
+        .map(|trx| {
+            let pool = pool_address((trx % POOLS as usize) as u8);
+            let logs = (0..LOGS_PER_TRANSACTION)
+                .map(|i| {
+                    let (address, topics, data) = match i {
+                        0 => (pool.clone(), vec![topics::lendergroup_v1::PAUSED.to_vec()], abi_address(&[0xab; 20])),
+                        1 => (pool.clone(), vec![topics::lendergroup_v1::UNPAUSED.to_vec()], abi_address(&[0xab; 20])),
+                        2 | 3 => (
+                            vec![0x20 + i as u8; 20],
+                            vec![topics::erc20::TRANSFER.to_vec(), abi_address(&[0x01; 20]), abi_address(&[0x02; 20])],
+                            vec![0u8; 32],
+                        ),
+                        _ => (vec![0x30 + i as u8; 20], vec![vec![i as u8; 32]], vec![0u8; 64]),
+                    };
+                    ordinal += 1;
+                    block_index += 1;
+                    eth::Log {
+                        address,
+                        topics,
+                        data,
+                        index: i as u32,
+                        block_index,
+                        ordinal,
+                    }
+                })
+                .collect();
+            eth::TransactionTrace {
+                hash: vec![trx as u8; 32],
+                status: eth::TransactionTraceStatus::Succeeded as i32,
+                receipt: Some(eth::TransactionReceipt {
+                    logs,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    eth::Block {
+        number: 57233385,
+        header: Some(eth::BlockHeader {
+            timestamp: Some(prost_types::Timestamp {
+                seconds: 1_717_000_000,
+                nanos: 0,
+            }),
+            ..Default::default()
+        }),
+        transaction_traces,
+        ..Default::default()
+    }
+}
+
+fn tracked_factories() -> contract::TrackedFactories {
+    contract::TrackedFactories {
+        factories: vec![contract::TrackedFactory {
+            address: factory_address(),
+            start_block: 0,
+            version: contract::FactoryVersion::V1 as i32,
+        }],
+    }
+}
+
+fn dds_store() -> MemoryStore<contract::TrackedFactory> {
+    let factory = tracked_factories().factories[0].clone();
+    MemoryStore::new((0..POOLS).map(|pool| (dds_store_key(&pool_address(pool)), factory.clone())).collect())
+}
+
+fn pool_config_store() -> MemoryStore<contract::PoolConfig> {
+    MemoryStore::new(
+        (0..POOLS)
+            .map(|pool| {
+                let address = pool_address(pool);
+                (
+                    pool_configs::pool_config_key(&address),
+                    contract::PoolConfig {
+                        group_pool_address: pool_configs::pool_config_key(&address),
+                        principal_token_address: vec![0x40; 20],
+                        collateral_token_address: vec![0x41; 20],
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect(),
+    )
+}
+
+// map_events before the single pass: every event type walked the block logs and read the pool store
+// for every log before trying its own decoder
+fn map_v1_events_per_event_type(
+    blk: &eth::Block,
+    tracked_factories: &contract::TrackedFactories,
+    dds_store: &impl StoreLookup<contract::TrackedFactory>,
+    pool_configs: &impl StoreLookup<contract::PoolConfig>,
+    share_tokens: &impl StoreLookup<String>,
+    events: &mut contract::Events,
+) {
+    let topic0 = |log: &eth::Log| <[u8; 32]>::try_from(log.topics.first()?.as_slice()).ok();
+
+    for factory_topic in [
+        topics::factory_v1::ADMIN_CHANGED,
+        topics::factory_v1::BEACON_UPGRADED,
+        topics::factory_v1::DEPLOYED_LENDER_GROUP_CONTRACT,
+        topics::factory_v1::UPGRADED,
+    ] {
+        for view in blk.receipts() {
+            for log in view.receipt.logs.iter() {
+                if let Some(factory) = factories::tracked_factory(tracked_factories, &log.address) {
+                    if topic0(log) == Some(factory_topic) {
+                        map_factory_v1_log(blk, view.transaction, log, factory_topic, factory, events);
+                    }
+                }
+            }
+        }
+    }
+
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            if let Some(pool_address) = share_tokens.lookup_at(log.ordinal, &shares::share_token_key(&log.address)) {
+                let pool_config = pool_configs.lookup_at(log.ordinal, &pool_address).unwrap_or_default();
+                shares::map_share_transfer_log(blk, view.transaction, log, &pool_address, &pool_config, events);
+            }
+        }
+    }
+
+    for event_topic in topics::lendergroup_v1::ALL {
+        for view in blk.receipts() {
+            for log in view.receipt.logs.iter() {
+                if let Some(factory) = declared_dds_factory(&log.address, log.ordinal, dds_store) {
+                    if topic0(log) == Some(event_topic) {
+                        let pool_config = pool_configs
+                            .lookup_at(log.ordinal, &pool_configs::pool_config_key(&log.address))
+                            .unwrap_or_default();
+                        map_lendergroup_v1_log(blk, view.transaction, log, event_topic, factory, &pool_config, events);
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Run {
+    events: contract::Events,
+    elapsed: Duration,
+    store_reads: usize,
+}
+
+fn run(
+    map: impl Fn(
+        &eth::Block,
+        &contract::TrackedFactories,
+        &MemoryStore<contract::TrackedFactory>,
+        &MemoryStore<contract::PoolConfig>,
+        &MemoryStore<String>,
+        &mut contract::Events,
+    ),
+) -> Run {
+    let blk = synthetic_block();
+    let tracked_factories = tracked_factories();
+    let dds_store = dds_store();
+    let pool_configs = pool_config_store();
+    let share_tokens = MemoryStore::new(BTreeMap::new());
+
+    let mut events = contract::Events::default();
+    let started = Instant::now();
+    for _ in 0..ITERATIONS {
+        events = contract::Events::default();
+        map(&blk, &tracked_factories, &dds_store, &pool_configs, &share_tokens, &mut events);
+    }
+    Run {
+        events,
+        elapsed: started.elapsed() / ITERATIONS,
+        store_reads: (dds_store.reads.get() + pool_configs.reads.get() + share_tokens.reads.get()) / ITERATIONS as usize,
+    }
+}
+
+#[test]
+#[ignore]
+fn dispatch_bench() {
+    let per_event_type = run(|blk, tracked, dds, pools, shares, events| {
+        map_v1_events_per_event_type(blk, tracked, dds, pools, shares, events)
+    });
+    let single_pass = run(|blk, tracked, dds, pools, shares, events| map_v1_events(blk, tracked, dds, pools, shares, events));
+
+    assert_eq!(per_event_type.events, single_pass.events);
+    assert_eq!(single_pass.events.lendergroup_pauseds.len(), TRANSACTIONS);
+    assert_eq!(single_pass.events.lendergroup_unpauseds.len(), TRANSACTIONS);
+
+    println!(
+        "{} logs per block: per event type loops {:?} ({} store reads), single pass {:?} ({} store reads)",
+        TRANSACTIONS * LOGS_PER_TRANSACTION,
+        per_event_type.elapsed,
+        per_event_type.store_reads,
+        single_pass.elapsed,
+        single_pass.store_reads,
+    );
+    assert!(single_pass.store_reads < per_event_type.store_reads);
+}
@@ -0,0 +1,272 @@
+use crate::data_points::DAY_IN_SECONDS;
+use crate::pb::contract::v1 as contract;
+use contract::ShareExchangeRateSource;
+use std::collections::{BTreeMap, HashSet};
+use std::str::FromStr;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{
+    DeltaBigDecimal, DeltaProto, Deltas, StoreDelete, StoreGet, StoreGetBigDecimal, StoreMax, StoreMaxBigDecimal, StoreMin,
+    StoreMinBigDecimal, StoreSet, StoreSetIfNotExists, StoreSetIfNotExistsBigDecimal, StoreSetProto,
+};
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+pub fn source_label(source: ShareExchangeRateSource) -> &'static str {
+    match source {
+        ShareExchangeRateSource::LenderAddedPrincipal => "lender_added_principal",
+        ShareExchangeRateSource::EarningsWithdrawn => "earnings_withdrawn",
+        ShareExchangeRateSource::Unspecified => "unspecified",
+    }
+}
+
+// candle:{group pool address}:{period start}, same key in the open / high / low stores
+pub fn candle_key(pool_address: &str, period_start: u64) -> String {
+    format!("candle:{}:{}", pool_address, period_start)
+}
+
+fn day_start(block_time: u64) -> u64 {
+    block_time - block_time % DAY_IN_SECONDS
+}
+
+// principal per share, None when no shares were minted or burned
+fn implied_rate(principal_amount: &str, shares_amount: &str) -> Option<BigDecimal> {
+    let shares = BigInt::from_str(shares_amount).unwrap();
+    if shares == BigInt::zero() {
+        return None;
+    }
+    Some(BigInt::from_str(principal_amount).unwrap().to_decimal(0) / shares.to_decimal(0))
+}
+
+pub fn map_share_exchange_rates(events: &contract::Events) -> contract::ShareExchangeRates {
+    let mut rates: Vec<contract::ShareExchangeRate> = vec![];
+
+    rates.extend(events.lendergroup_lender_added_principals.iter().filter_map(|evt| {
+        let exchange_rate = implied_rate(&evt.amount, &evt.shares_amount)?;
+        Some(contract::ShareExchangeRate {
+            group_pool_address: evt.evt_address.clone(),
+            evt_tx_hash: evt.evt_tx_hash.clone(),
+            evt_index: evt.evt_index,
+            evt_ordinal: evt.evt_ordinal,
+            evt_block_time: evt.evt_block_time,
+            evt_block_number: evt.evt_block_number,
+            source: ShareExchangeRateSource::LenderAddedPrincipal as i32,
+            principal_amount: evt.amount.clone(),
+            shares_amount: evt.shares_amount.clone(),
+            exchange_rate: exchange_rate.to_string(),
+        })
+    }));
+    rates.extend(events.lendergroup_earnings_withdrawns.iter().filter_map(|evt| {
+        let exchange_rate = implied_rate(&evt.principal_tokens_withdrawn, &evt.amount_pool_shares_tokens)?;
+        Some(contract::ShareExchangeRate {
+            group_pool_address: evt.evt_address.clone(),
+            evt_tx_hash: evt.evt_tx_hash.clone(),
+            evt_index: evt.evt_index,
+            evt_ordinal: evt.evt_ordinal,
+            evt_block_time: evt.evt_block_time,
+            evt_block_number: evt.evt_block_number,
+            source: ShareExchangeRateSource::EarningsWithdrawn as i32,
+            principal_amount: evt.principal_tokens_withdrawn.clone(),
+            shares_amount: evt.amount_pool_shares_tokens.clone(),
+            exchange_rate: exchange_rate.to_string(),
+        })
+    }));
+
+    // candles and the latest rate follow the order of the events in the block
+    rates.sort_by_key(|rate| rate.evt_index);
+
+    contract::ShareExchangeRates { rates }
+}
+
+pub fn set_latest_share_exchange_rates(rates: &contract::ShareExchangeRates, store: &StoreSetProto<contract::ShareExchangeRate>) {
+    rates.rates.iter().for_each(|rate| {
+        store.set(rate.evt_ordinal, rate.group_pool_address.clone(), rate);
+    });
+}
+
+// a pool's candle of the previous day is done once its latest rate moves to a new day, drop it from the
+// open / high / low stores so they only hold the current day of every pool
+pub fn delete_previous_candles<S: StoreDelete>(latest_rates: &Deltas<DeltaProto<contract::ShareExchangeRate>>, store: &S) {
+    latest_rates.deltas.iter().for_each(|delta| {
+        let previous_day = day_start(delta.old_value.evt_block_time);
+        if delta.old_value.evt_block_time != 0 && previous_day != day_start(delta.new_value.evt_block_time) {
+            store.delete_prefix(delta.ordinal as i64, &candle_key(&delta.old_value.group_pool_address, previous_day));
+        }
+    });
+}
+
+pub fn set_candle_opens(rates: &contract::ShareExchangeRates, store: &StoreSetIfNotExistsBigDecimal) {
+    rates.rates.iter().for_each(|rate| {
+        store.set_if_not_exists(
+            rate.evt_ordinal,
+            candle_key(&rate.group_pool_address, day_start(rate.evt_block_time)),
+            &BigDecimal::from_str(&rate.exchange_rate).unwrap(),
+        );
+    });
+}
+
+pub fn max_candle_highs(rates: &contract::ShareExchangeRates, store: &StoreMaxBigDecimal) {
+    rates.rates.iter().for_each(|rate| {
+        store.max(
+            rate.evt_ordinal,
+            candle_key(&rate.group_pool_address, day_start(rate.evt_block_time)),
+            BigDecimal::from_str(&rate.exchange_rate).unwrap(),
+        );
+    });
+}
+
+pub fn min_candle_lows(rates: &contract::ShareExchangeRates, store: &StoreMinBigDecimal) {
+    rates.rates.iter().for_each(|rate| {
+        store.min(
+            rate.evt_ordinal,
+            candle_key(&rate.group_pool_address, day_start(rate.evt_block_time)),
+            BigDecimal::from_str(&rate.exchange_rate).unwrap(),
+        );
+    });
+}
+
+// candles of the pools with a rate in this block, the close is the block's last rate of the pool
+pub fn map_share_exchange_rate_candles(
+    block_number: u64,
+    block_time: u64,
+    rates: &contract::ShareExchangeRates,
+    opens: &StoreGetBigDecimal,
+    highs: &StoreGetBigDecimal,
+    lows: &StoreGetBigDecimal,
+) -> contract::ShareExchangeRateCandles {
+    let mut closes: BTreeMap<String, &contract::ShareExchangeRate> = BTreeMap::new();
+    rates.rates.iter().for_each(|rate| {
+        closes.insert(rate.group_pool_address.clone(), rate);
+    });
+
+    let period_start = day_start(block_time);
+    contract::ShareExchangeRateCandles {
+        candles: closes
+            .into_iter()
+            .map(|(pool_address, close)| {
+                let key = candle_key(&pool_address, period_start);
+                let get = |store: &StoreGetBigDecimal| {
+                    store
+                        .get_last(&key)
+                        .map(|rate| rate.to_string())
+                        .unwrap_or_else(|| close.exchange_rate.clone())
+                };
+                contract::ShareExchangeRateCandle {
+                    group_pool_address: pool_address.clone(),
+                    period_start,
+                    open: get(opens),
+                    high: get(highs),
+                    low: get(lows),
+                    close: close.exchange_rate.clone(),
+                    block_number,
+                    block_time,
+                }
+            })
+            .collect(),
+    }
+}
+
+pub fn db_share_exchange_rates_out(
+    rates: &contract::ShareExchangeRates,
+    candles: &contract::ShareExchangeRateCandles,
+    tables: &mut DatabaseChangeTables,
+) {
+    rates.rates.iter().for_each(|rate| {
+        tables
+            .create_row("share_exchange_rate", [("evt_tx_hash", rate.evt_tx_hash.to_string()),("evt_index", rate.evt_index.to_string())])
+            .set("evt_block_time", rate.evt_block_time)
+            .set("evt_block_number", rate.evt_block_number)
+            .set("group_pool_address", &rate.group_pool_address)
+            .set("source", source_label(rate.source()).to_string())
+            .set("principal_amount", BigDecimal::from_str(&rate.principal_amount).unwrap())
+            .set("shares_amount", BigDecimal::from_str(&rate.shares_amount).unwrap())
+            .set("exchange_rate", BigDecimal::from_str(&rate.exchange_rate).unwrap());
+    });
+    // upserted so the last block of the day wins
+    candles.candles.iter().for_each(|candle| {
+        tables
+            .upsert_row(
+                "share_exchange_rate_candle",
+                [
+                    ("group_pool_address", candle.group_pool_address.to_string()),
+                    ("period_start", candle.period_start.to_string()),
+                ],
+            )
+            .set("open", BigDecimal::from_str(&candle.open).unwrap())
+            .set("high", BigDecimal::from_str(&candle.high).unwrap())
+            .set("low", BigDecimal::from_str(&candle.low).unwrap())
+            .set("close", BigDecimal::from_str(&candle.close).unwrap())
+            .set("block_number", candle.block_number)
+            .set("block_time", candle.block_time);
+    });
+}
+
+pub fn graph_share_exchange_rates_out(
+    rates: &contract::ShareExchangeRates,
+    candles: &contract::ShareExchangeRateCandles,
+    candle_opens_deltas: &Deltas<DeltaBigDecimal>,
+    tables: &mut EntityChangesTables,
+) {
+    rates.rates.iter().for_each(|rate| {
+        tables
+            .create_row("share_exchange_rate", format!("{}-{}", rate.evt_tx_hash, rate.evt_index))
+            .set("evt_tx_hash", &rate.evt_tx_hash)
+            .set("evt_index", rate.evt_index)
+            .set("evt_block_time", rate.evt_block_time)
+            .set("evt_block_number", rate.evt_block_number)
+            .set("group_pool_address", &rate.group_pool_address)
+            .set("source", source_label(rate.source()).to_string())
+            .set("principal_amount", BigInt::from_str(&rate.principal_amount).unwrap())
+            .set("shares_amount", BigInt::from_str(&rate.shares_amount).unwrap())
+            .set("exchange_rate", BigDecimal::from_str(&rate.exchange_rate).unwrap());
+    });
+    // the first rate of the pool's day sets the open and creates the candle, it's then updated so the last block
+    // of the day wins
+    let opened: HashSet<&str> = candle_opens_deltas
+        .deltas
+        .iter()
+        .filter(|delta| delta.operation == Operation::Create)
+        .map(|delta| delta.key.as_str())
+        .collect();
+    candles.candles.iter().for_each(|candle| {
+        let id = format!("{}-{}", candle.group_pool_address, candle.period_start);
+        let row = if opened.contains(candle_key(&candle.group_pool_address, candle.period_start).as_str()) {
+            tables.create_row("share_exchange_rate_candle", id)
+        } else {
+            tables.update_row("share_exchange_rate_candle", id)
+        };
+        row.set("group_pool_address", &candle.group_pool_address)
+            .set("period_start", BigInt::from(candle.period_start))
+            .set("open", BigDecimal::from_str(&candle.open).unwrap())
+            .set("high", BigDecimal::from_str(&candle.high).unwrap())
+            .set("low", BigDecimal::from_str(&candle.low).unwrap())
+            .set("close", BigDecimal::from_str(&candle.close).unwrap())
+            .set("block_number", BigInt::from(candle.block_number))
+            .set("block_time", BigInt::from(candle.block_time));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implied_rate_is_principal_per_share() {
+        assert_eq!(implied_rate("3000000", "2000000"), Some(BigDecimal::from_str("1.5").unwrap()));
+        assert_eq!(implied_rate("1", "4"), Some(BigDecimal::from_str("0.25").unwrap()));
+        assert_eq!(implied_rate("0", "10"), Some(BigDecimal::from_str("0").unwrap()));
+    }
+
+    #[test]
+    fn no_implied_rate_without_shares() {
+        assert_eq!(implied_rate("1000", "0"), None);
+        assert_eq!(implied_rate("0", "0"), None);
+    }
+
+    #[test]
+    fn candles_start_at_the_utc_day() {
+        assert_eq!(day_start(1_717_000_000), 1_716_940_800);
+        assert_eq!(day_start(1_716_940_800), 1_716_940_800);
+        assert_eq!(candle_key("abcd", 1_716_940_800), "candle:abcd:1716940800");
+    }
+}
@@ -0,0 +1,172 @@
+use crate::abi::factory_contract::functions;
+use crate::pb::contract::v1 as contract;
+use contract::FactoryVersion;
+use substreams::errors::Error;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/*
+
+Tracked factories are passed in through the `map_tracked_factories` params as a
+comma separated list of `[<version>:]<factory address>@<start block>` entries, e.g.
+
+  e00384587dc733d1e201e1eaa5583645d351c01c@57233385,v1:0x1234...abcd@18000000
+
+The `0x` prefix and the `@<start block>` suffix are optional (start block defaults to 0),
+and the version defaults to the first factory generation (`v1`).
+
+*/
+
+pub fn version_label(version: FactoryVersion) -> &'static str {
+    match version {
+        FactoryVersion::V1 => "v1",
+        FactoryVersion::Unspecified => "unspecified",
+    }
+}
+
+// only the first generation has its ABI modules generated from abi/ (see build.rs)
+fn parse_version(label: &str) -> Option<FactoryVersion> {
+    match label.to_lowercase().as_str() {
+        "v1" => Some(FactoryVersion::V1),
+        _ => None,
+    }
+}
+
+pub fn parse_tracked_factories(params: &str) -> Result<Vec<contract::TrackedFactory>, Error> {
+    let mut factories: Vec<contract::TrackedFactory> = Vec::new();
+
+    for entry in params.split(',').map(|entry| entry.trim()).filter(|entry| !entry.is_empty()) {
+        let (version, entry) = match entry.split_once(':') {
+            Some((raw_version, rest)) => {
+                let version = parse_version(raw_version.trim()).ok_or_else(|| {
+                    Error::msg(format!(
+                        "unknown factory version `{}` in params, expected {}",
+                        raw_version,
+                        version_label(FactoryVersion::V1)
+                    ))
+                })?;
+                (version, rest.trim())
+            }
+            None => (FactoryVersion::V1, entry),
+        };
+
+        let (raw_address, raw_start_block) = match entry.split_once('@') {
+            Some((address, start_block)) => (address.trim(), Some(start_block.trim())),
+            None => (entry, None),
+        };
+
+        let address = hex::decode(raw_address.trim_start_matches("0x")).map_err(|e| {
+            Error::msg(format!("invalid factory address `{}` in params: {}", raw_address, e))
+        })?;
+        if address.len() != 20 {
+            return Err(Error::msg(format!(
+                "invalid factory address `{}` in params: expected 20 bytes, got {}",
+                raw_address,
+                address.len()
+            )));
+        }
+
+        let start_block = match raw_start_block {
+            Some(raw) => raw.parse::<u64>().map_err(|e| {
+                Error::msg(format!("invalid start block `{}` for factory `{}` in params: {}", raw, raw_address, e))
+            })?,
+            None => 0,
+        };
+
+        if factories.iter().any(|factory| factory.address == address) {
+            return Err(Error::msg(format!("factory `{}` is declared more than once in params", Hex(&address))));
+        }
+
+        factories.push(contract::TrackedFactory {
+            address,
+            start_block,
+            version: version as i32,
+        });
+    }
+
+    if factories.is_empty() {
+        return Err(Error::msg(
+            "no factory address declared in params, pass them with -p map_tracked_factories=<factory address>@<start block>",
+        ));
+    }
+
+    Ok(factories)
+}
+
+// map_tracked_factories only forwards factories whose start block has been reached
+pub fn tracked_factory<'a>(factories: &'a contract::TrackedFactories, addr: &[u8]) -> Option<&'a contract::TrackedFactory> {
+    factories.factories.iter().find(|factory| factory.address == addr)
+}
+
+// inputs of the factory call that emitted a DeployedLenderGroupContract log: the innermost
+// deployLenderCommitmentGroupPool call of the factory whose ordinal range contains the log
+pub fn v1_deploy_call(
+    trx: &eth::TransactionTrace,
+    log: &eth::Log,
+) -> Option<contract::FactoryDeployLenderCommitmentGroupPoolCall> {
+    let call = trx
+        .calls
+        .iter()
+        .filter(|call| call.address == log.address && call.begin_ordinal <= log.ordinal && log.ordinal <= call.end_ordinal)
+        .filter(|call| functions::DeployLenderCommitmentGroupPool::match_call(call))
+        .max_by_key(|call| call.depth)?;
+    let decoded = functions::DeployLenderCommitmentGroupPool::decode(call).ok()?;
+
+    Some(contract::FactoryDeployLenderCommitmentGroupPoolCall {
+        initial_principal_amount: decoded.u_initial_principal_amount.to_string(),
+        principal_token_address: decoded.u_principal_token_address,
+        collateral_token_address: decoded.u_collateral_token_address,
+        market_id: decoded.u_market_id.to_string(),
+        max_loan_duration: decoded.u_max_loan_duration.to_u64(),
+        interest_rate_lower_bound: decoded.u_interest_rate_lower_bound.to_u64(),
+        interest_rate_upper_bound: decoded.u_interest_rate_upper_bound.to_u64(),
+        liquidity_threshold_percent: decoded.u_liquidity_threshold_percent.to_u64(),
+        loan_to_value_percent: decoded.u_loan_to_value_percent.to_u64(),
+        uniswap_pool_fee: decoded.u_uniswap_pool_fee.to_u64(),
+        twap_interval: decoded.u_twap_interval.to_u64(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FACTORY: &str = "e00384587dc733d1e201e1eaa5583645d351c01c";
+
+    #[test]
+    fn parses_address_start_block_and_version() {
+        let factories = parse_tracked_factories(&format!(" 0x{}@57233385 ", FACTORY)).unwrap();
+        assert_eq!(factories.len(), 1);
+        assert_eq!(Hex(&factories[0].address).to_string(), FACTORY);
+        assert_eq!(factories[0].start_block, 57233385);
+        assert_eq!(factories[0].version(), FactoryVersion::V1);
+
+        let factories = parse_tracked_factories(&format!("V1:{}", FACTORY)).unwrap();
+        assert_eq!(factories[0].start_block, 0);
+        assert_eq!(factories[0].version(), FactoryVersion::V1);
+    }
+
+    #[test]
+    fn skips_empty_entries() {
+        let other = "1234567890abcdef1234567890abcdef12345678";
+        let factories = parse_tracked_factories(&format!(",{}@1,, {}@2,", FACTORY, other)).unwrap();
+        assert_eq!(factories.len(), 2);
+        assert_eq!(factories[1].start_block, 2);
+    }
+
+    #[test]
+    fn rejects_empty_params() {
+        assert!(parse_tracked_factories("").is_err());
+        assert!(parse_tracked_factories(" , ").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        assert!(parse_tracked_factories("v2:e00384587dc733d1e201e1eaa5583645d351c01c").is_err());
+        assert!(parse_tracked_factories("zz00384587dc733d1e201e1eaa5583645d351c01c").is_err());
+        assert!(parse_tracked_factories("e00384587dc733d1e201e1eaa5583645d351c0").is_err());
+        assert!(parse_tracked_factories(&format!("{}@", FACTORY)).is_err());
+        assert!(parse_tracked_factories(&format!("{}@-1", FACTORY)).is_err());
+        assert!(parse_tracked_factories(&format!("{}@1,{}@2", FACTORY, FACTORY)).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::pb::contract::v1 as contract;
+use std::str::FromStr;
+use substreams::key;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt};
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+pub const TOTAL_PRINCIPAL_TOKENS_COMMITTED: &str = "total_principal_tokens_committed";
+pub const TOTAL_PRINCIPAL_TOKENS_WITHDRAWN: &str = "total_principal_tokens_withdrawn";
+pub const SHARES_TOKENS_NET: &str = "shares_tokens_net";
+
+// lender:{group pool address}:{lender address}:{total}
+pub fn lender_total_key(pool_address: &str, lender_address: &str, total: &str) -> String {
+    format!("lender:{}:{}:{}", pool_address, lender_address, total)
+}
+
+// every total is touched (possibly with zero) so the deltas always carry a full lender position
+fn add_lender_position(
+    store: &StoreAddBigInt,
+    ordinal: u64,
+    pool_address: &str,
+    lender: &[u8],
+    committed: BigInt,
+    withdrawn: BigInt,
+    shares: BigInt,
+) {
+    let lender_address = Hex(lender).to_string();
+    store.add(ordinal, lender_total_key(pool_address, &lender_address, TOTAL_PRINCIPAL_TOKENS_COMMITTED), committed);
+    store.add(ordinal, lender_total_key(pool_address, &lender_address, TOTAL_PRINCIPAL_TOKENS_WITHDRAWN), withdrawn);
+    store.add(ordinal, lender_total_key(pool_address, &lender_address, SHARES_TOKENS_NET), shares);
+}
+
+pub fn add_lender_totals(events: &contract::Events, store: &StoreAddBigInt) {
+    events.lendergroup_lender_added_principals.iter().for_each(|evt| {
+        // the lender commits the principal, the shares go to the recipient (often the same account)
+        add_lender_position(
+            store,
+            evt.evt_ordinal,
+            &evt.evt_address,
+            &evt.lender,
+            BigInt::from_str(&evt.amount).unwrap(),
+            BigInt::zero(),
+            BigInt::zero(),
+        );
+        add_lender_position(
+            store,
+            evt.evt_ordinal,
+            &evt.evt_address,
+            &evt.shares_recipient,
+            BigInt::zero(),
+            BigInt::zero(),
+            BigInt::from_str(&evt.shares_amount).unwrap(),
+        );
+    });
+    events.lendergroup_earnings_withdrawns.iter().for_each(|evt| {
+        add_lender_position(
+            store,
+            evt.evt_ordinal,
+            &evt.evt_address,
+            &evt.lender,
+            BigInt::zero(),
+            BigInt::from_str(&evt.principal_tokens_withdrawn).unwrap(),
+            BigInt::zero() - BigInt::from_str(&evt.amount_pool_shares_tokens).unwrap(),
+        );
+    });
+}
+
+pub fn db_lender_metrics_out(lender_totals_deltas: &Deltas<DeltaBigInt>, tables: &mut DatabaseChangeTables) {
+    lender_totals_deltas.deltas.iter().for_each(|delta| {
+        let pool_address = key::segment_at(&delta.key, 1);
+        let lender_address = key::segment_at(&delta.key, 2);
+        tables
+            .upsert_row(
+                "group_lender_metrics",
+                [
+                    ("group_pool_address", pool_address.to_string()),
+                    ("user_lender_address", lender_address.to_string()),
+                ],
+            )
+            .set(key::last_segment(&delta.key), delta.new_value.clone());
+    });
+}
+
+pub fn graph_lender_metrics_out(lender_totals_deltas: &Deltas<DeltaBigInt>, tables: &mut EntityChangesTables) {
+    lender_totals_deltas.deltas.iter().for_each(|delta| {
+        let pool_address = key::segment_at(&delta.key, 1);
+        let lender_address = key::segment_at(&delta.key, 2);
+        let id = format!("{}-{}", pool_address, lender_address);
+        // the first position of a lender creates all of its totals at once
+        let row = match delta.operation {
+            Operation::Create => tables.create_row("group_lender_metrics", id),
+            _ => tables.update_row("group_lender_metrics", id),
+        };
+        row.set("group_pool_address", pool_address.to_string())
+            .set("user_lender_address", lender_address.to_string())
+            .set(key::last_segment(&delta.key), delta.new_value.clone());
+    });
+}
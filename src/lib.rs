@@ -1,7 +1,28 @@
 mod abi;
+mod amounts;
+mod calls;
+mod collateral_audits;
+mod collateral_prices;
+mod data_points;
+#[cfg(test)]
+mod dispatch_bench;
+mod exchange_rates;
+mod factories;
+mod lender_metrics;
+mod loan_health;
+mod loans;
 mod pb;
+mod pool_configs;
+mod pool_metrics;
+mod reconciliation;
+mod reverted_calls;
 mod rpc;
-use hex_literal::hex;
+mod rpc_failures;
+mod shares;
+mod snapshots;
+mod teller_v2;
+mod tokens;
+mod topics;
 use pb::contract::v1 as contract;
 use substreams::prelude::*;
 use substreams::store;
@@ -20,368 +41,425 @@ use substreams::scalar::BigDecimal;
 
 substreams_ethereum::init!();
 
-const FACTORY_TRACKED_CONTRACT: [u8; 20] = hex!("e00384587dc733d1e201e1eaa5583645d351c01c");
-
-fn map_factory_events(blk: &eth::Block, events: &mut contract::Events) {
-    events.factory_admin_changeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == FACTORY_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::factory_contract::events::AdminChanged::match_and_decode(log) {
-                        return Some(contract::FactoryAdminChanged {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            new_admin: event.new_admin,
-                            previous_admin: event.previous_admin,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.factory_beacon_upgradeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == FACTORY_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::factory_contract::events::BeaconUpgraded::match_and_decode(log) {
-                        return Some(contract::FactoryBeaconUpgraded {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            beacon: event.beacon,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.factory_deployed_lender_group_contracts.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == FACTORY_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::factory_contract::events::DeployedLenderGroupContract::match_and_decode(log) {
-                        return Some(contract::FactoryDeployedLenderGroupContract {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            group_contract: event.group_contract,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.factory_upgradeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == FACTORY_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::factory_contract::events::Upgraded::match_and_decode(log) {
-                        return Some(contract::FactoryUpgraded {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            implementation: event.implementation,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-}
-
-fn is_declared_dds_address(addr: &Vec<u8>, ordinal: u64, dds_store: &store::StoreGetInt64) -> bool {
-    //    substreams::log::info!("Checking if address {} is declared dds address", Hex(addr).to_string());
-    if dds_store.get_at(ordinal, Hex(addr).to_string()).is_some() {
-        return true;
+// single pass over the block logs: factory logs are matched on their address, and only logs
+// carrying a lender group event (or shares token Transfer) topic0 are looked up (once) in the pool stores
+fn map_v1_events(
+    blk: &eth::Block,
+    tracked_factories: &contract::TrackedFactories,
+    dds_store: &impl StoreLookup<contract::TrackedFactory>,
+    pool_configs: &impl StoreLookup<contract::PoolConfig>,
+    share_tokens: &impl StoreLookup<String>,
+    events: &mut contract::Events,
+) {
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(Ok(topic0)) = log.topics.first().map(|topic| <[u8; 32]>::try_from(topic.as_slice())) else {
+                continue;
+            };
+
+            if let Some(factory) = factories::tracked_factory(tracked_factories, &log.address) {
+                map_factory_v1_log(blk, view.transaction, log, topic0, factory, events);
+                continue;
+            }
+
+            if topic0 == topics::erc20::TRANSFER {
+                // shares tokens are registered by store_share_tokens at their pool's PoolInitialized log
+                if let Some(pool_address) = share_tokens.lookup_at(log.ordinal, &shares::share_token_key(&log.address)) {
+                    // the store value is the pool address, already in pool_config_key form
+                    let pool_config = pool_configs.lookup_at(log.ordinal, &pool_address).unwrap_or_default();
+                    shares::map_share_transfer_log(blk, view.transaction, log, &pool_address, &pool_config, events);
+                }
+                continue;
+            }
+
+            if !topics::lendergroup_v1::ALL.contains(&topic0) {
+                continue;
+            }
+
+            if let Some(factory) = declared_dds_factory(&log.address, log.ordinal, dds_store) {
+                // set by store_pool_configs at the PoolInitialized log, empty for pool logs before it
+                let pool_config = pool_configs
+                    .lookup_at(log.ordinal, &pool_configs::pool_config_key(&log.address))
+                    .unwrap_or_default();
+                map_lendergroup_v1_log(blk, view.transaction, log, topic0, factory, &pool_config, events);
+            }
+        }
+    }
+}
+
+// call traces (reverted ones included) whose callee is a group pool of a tracked factory
+fn map_v1_calls(
+    blk: &eth::Block,
+    dds_store: &store::StoreGetProto<contract::TrackedFactory>,
+    calls: &mut contract::Calls,
+) {
+    for view in blk.calls() {
+        if let Some(factory) = declared_dds_factory(&view.call.address, view.call.begin_ordinal, dds_store) {
+            let decoded = calls::decode_lendergroup_call(view.call);
+            // failed calls also go to calls.reverted_calls, see reverted_calls
+            reverted_calls::map_lendergroup_v1_reverted_call(
+                blk,
+                view.transaction,
+                view.call,
+                decoded.as_ref(),
+                &factory,
+                &mut calls.reverted_calls,
+            );
+            if let Some(decoded) = decoded {
+                calls::map_lendergroup_v1_call(blk, view.transaction, view.call, decoded, factory, calls);
+            }
+        }
     }
-    return false;
 }
 
-fn map_lendergroup_events(
+fn map_factory_v1_log(
     blk: &eth::Block,
-    dds_store: &store::StoreGetInt64,
+    trx: &eth::TransactionTrace,
+    log: &eth::Log,
+    topic0: [u8; 32],
+    factory: &contract::TrackedFactory,
     events: &mut contract::Events,
 ) {
+    match topic0 {
+        topics::factory_v1::ADMIN_CHANGED => {
+            if let Some(event) = abi::factory_contract::events::AdminChanged::match_and_decode(log) {
+                events.factory_admin_changeds.push(contract::FactoryAdminChanged {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address.clone(),
+                    factory_version: factory.version,
+                    new_admin: event.new_admin,
+                    previous_admin: event.previous_admin,
+                });
+            }
+        }
+        topics::factory_v1::BEACON_UPGRADED => {
+            if let Some(event) = abi::factory_contract::events::BeaconUpgraded::match_and_decode(log) {
+                events.factory_beacon_upgradeds.push(contract::FactoryBeaconUpgraded {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address.clone(),
+                    factory_version: factory.version,
+                    beacon: event.beacon,
+                });
+            }
+        }
+        topics::factory_v1::DEPLOYED_LENDER_GROUP_CONTRACT => {
+            if let Some(event) = abi::factory_contract::events::DeployedLenderGroupContract::match_and_decode(log) {
+                events.factory_deployed_lender_group_contracts.push(contract::FactoryDeployedLenderGroupContract {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address.clone(),
+                    factory_version: factory.version,
+                    group_contract: event.group_contract,
+                    deployer: trx.from.clone(),
+                    deploy_call: factories::v1_deploy_call(trx, log),
+                });
+            }
+        }
+        topics::factory_v1::UPGRADED => {
+            if let Some(event) = abi::factory_contract::events::Upgraded::match_and_decode(log) {
+                events.factory_upgradeds.push(contract::FactoryUpgraded {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address.clone(),
+                    factory_version: factory.version,
+                    implementation: event.implementation,
+                });
+            }
+        }
+        _ => {}
+    }
+}
 
-    events.lendergroup_borrower_accepted_funds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::BorrowerAcceptedFunds::match_and_decode(log) {
-                        return Some(contract::LendergroupBorrowerAcceptedFunds {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            bid_id: event.bid_id.to_string(),
-                            borrower: event.borrower,
-                            collateral_amount: event.collateral_amount.to_string(),
-                            interest_rate: event.interest_rate.to_u64(),
-                            loan_duration: event.loan_duration.to_u64(),
-                            principal_amount: event.principal_amount.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_defaulted_loan_liquidateds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::DefaultedLoanLiquidated::match_and_decode(log) {
-                        return Some(contract::LendergroupDefaultedLoanLiquidated {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            amount_due: event.amount_due.to_string(),
-                            bid_id: event.bid_id.to_string(),
-                            liquidator: event.liquidator,
-                            token_amount_difference: event.token_amount_difference.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_earnings_withdrawns.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::EarningsWithdrawn::match_and_decode(log) {
-                        return Some(contract::LendergroupEarningsWithdrawn {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            amount_pool_shares_tokens: event.amount_pool_shares_tokens.to_string(),
-                            lender: event.lender,
-                            principal_tokens_withdrawn: event.principal_tokens_withdrawn.to_string(),
-                            recipient: event.recipient,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_initializeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::Initialized::match_and_decode(log) {
-                        return Some(contract::LendergroupInitialized {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            version: event.version.to_u64(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_lender_added_principals.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::LenderAddedPrincipal::match_and_decode(log) {
-                        return Some(contract::LendergroupLenderAddedPrincipal {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            amount: event.amount.to_string(),
-                            lender: event.lender,
-                            shares_amount: event.shares_amount.to_string(),
-                            shares_recipient: event.shares_recipient,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_loan_repaids.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::LoanRepaid::match_and_decode(log) {
-                        return Some(contract::LendergroupLoanRepaid {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            bid_id: event.bid_id.to_string(),
-                            interest_amount: event.interest_amount.to_string(),
-                            principal_amount: event.principal_amount.to_string(),
-                            repayer: event.repayer,
-                            total_interest_collected: event.total_interest_collected.to_string(),
-                            total_principal_repaid: event.total_principal_repaid.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_ownership_transferreds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::OwnershipTransferred::match_and_decode(log) {
-                        return Some(contract::LendergroupOwnershipTransferred {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            new_owner: event.new_owner,
-                            previous_owner: event.previous_owner,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_pauseds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::Paused::match_and_decode(log) {
-                        return Some(contract::LendergroupPaused {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            account: event.account,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_pool_initializeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::PoolInitialized::match_and_decode(log) {
-                        
-                        
-                       let lender_group_contract_address = Hex(&log.address).to_string();
-
-                        let fetched_rpc_data = rpc::fetch_lender_group_pool_initialization_data_from_rpc(
-                            &lender_group_contract_address
-                             ).unwrap();
-
-                        
-                        return Some(contract::LendergroupPoolInitialized {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            collateral_token_address: event.collateral_token_address,
-                            interest_rate_lower_bound: event.interest_rate_lower_bound.to_u64(),
-                            interest_rate_upper_bound: event.interest_rate_upper_bound.to_u64(),
-                            liquidity_threshold_percent: event.liquidity_threshold_percent.to_u64(),
-                            loan_to_value_percent: event.loan_to_value_percent.to_u64(),
-                            market_id: event.market_id.to_string(),
-                            max_loan_duration: event.max_loan_duration.to_u64(),
-                            pool_shares_token: event.pool_shares_token,
-                            principal_token_address: event.principal_token_address,
-                            twap_interval: event.twap_interval.to_u64(),
-                            uniswap_pool_fee: event.uniswap_pool_fee.to_u64(),
-
-                            teller_v2_address: fetched_rpc_data.teller_v2_address.to_fixed_bytes().to_vec(),
-                            uniswap_v3_pool_address: fetched_rpc_data.uniswap_v3_pool_address.to_fixed_bytes().to_vec(),
-                            smart_commitment_forwarder_address: fetched_rpc_data.smart_commitment_forwarder_address.to_fixed_bytes().to_vec(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-
-    events.lendergroup_unpauseds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| is_declared_dds_address(&log.address, log.ordinal, dds_store))
-                .filter_map(|log| {
-                    if let Some(event) = abi::lendergroup_contract::events::Unpaused::match_and_decode(log) {
-                        return Some(contract::LendergroupUnpaused {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: blk.timestamp_seconds(),
-                            evt_block_number: blk.number,
-                            evt_address: Hex(&log.address).to_string(),
-                            account: event.account,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
+// key under which store_factory_lendergroup_created records a deployed group contract
+fn dds_store_key(addr: &[u8]) -> String {
+    Hex(addr).to_string()
+}
+
+// store read at an ordinal. map_v1_events only reads its stores through it, so that dispatch_bench
+// can run the decoding on in-memory stores, outside of the substreams runtime
+trait StoreLookup<T> {
+    fn lookup_at(&self, ordinal: u64, key: &str) -> Option<T>;
+}
+
+impl<T: Default + prost::Message> StoreLookup<T> for store::StoreGetProto<T> {
+    fn lookup_at(&self, ordinal: u64, key: &str) -> Option<T> {
+        self.get_at(ordinal, key)
+    }
+}
+
+impl StoreLookup<String> for store::StoreGetString {
+    fn lookup_at(&self, ordinal: u64, key: &str) -> Option<String> {
+        self.get_at(ordinal, key)
+    }
+}
+
+// factory that deployed the given group contract, if it is one of the tracked factories' pools
+fn declared_dds_factory(
+    addr: &[u8],
+    ordinal: u64,
+    dds_store: &impl StoreLookup<contract::TrackedFactory>,
+) -> Option<contract::TrackedFactory> {
+    dds_store.lookup_at(ordinal, &dds_store_key(addr))
+}
+
+fn map_lendergroup_v1_log(
+    blk: &eth::Block,
+    trx: &eth::TransactionTrace,
+    log: &eth::Log,
+    topic0: [u8; 32],
+    factory: contract::TrackedFactory,
+    pool_config: &contract::PoolConfig,
+    events: &mut contract::Events,
+) {
+    match topic0 {
+        topics::lendergroup_v1::BORROWER_ACCEPTED_FUNDS => {
+            if let Some(event) = abi::lendergroup_contract::events::BorrowerAcceptedFunds::match_and_decode(log) {
+                events.lendergroup_borrower_accepted_funds.push(contract::LendergroupBorrowerAcceptedFunds {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    bid_id: event.bid_id.to_string(),
+                    borrower: event.borrower,
+                    collateral_amount: event.collateral_amount.to_string(),
+                    interest_rate: event.interest_rate.to_u64(),
+                    loan_duration: event.loan_duration.to_u64(),
+                    principal_amount: event.principal_amount.to_string(),
+                    principal_amount_normalized: amounts::normalized_amount(&event.principal_amount.to_string(), pool_config.principal_token_decimals),
+                    collateral_amount_normalized: amounts::normalized_amount(&event.collateral_amount.to_string(), pool_config.collateral_token_decimals),
+                    // filled by teller_v2::enrich_accepted_bids and collateral_audits::enrich_collateral_audits once the block is decoded
+                    teller_v2_bid: None,
+                    collateral_audit: None,
+                });
+            }
+        }
+        topics::lendergroup_v1::DEFAULTED_LOAN_LIQUIDATED => {
+            if let Some(event) = abi::lendergroup_contract::events::DefaultedLoanLiquidated::match_and_decode(log) {
+                events.lendergroup_defaulted_loan_liquidateds.push(contract::LendergroupDefaultedLoanLiquidated {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    amount_due: event.amount_due.to_string(),
+                    bid_id: event.bid_id.to_string(),
+                    liquidator: event.liquidator,
+                    token_amount_difference: event.token_amount_difference.to_string(),
+                    amount_due_normalized: amounts::normalized_amount(&event.amount_due.to_string(), pool_config.principal_token_decimals),
+                    token_amount_difference_normalized: amounts::normalized_amount(
+                        &event.token_amount_difference.to_string(),
+                        pool_config.principal_token_decimals,
+                    ),
+                });
+            }
+        }
+        topics::lendergroup_v1::EARNINGS_WITHDRAWN => {
+            if let Some(event) = abi::lendergroup_contract::events::EarningsWithdrawn::match_and_decode(log) {
+                events.lendergroup_earnings_withdrawns.push(contract::LendergroupEarningsWithdrawn {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    amount_pool_shares_tokens: event.amount_pool_shares_tokens.to_string(),
+                    lender: event.lender,
+                    principal_tokens_withdrawn: event.principal_tokens_withdrawn.to_string(),
+                    recipient: event.recipient,
+                    amount_pool_shares_tokens_normalized: amounts::normalized_amount(
+                        &event.amount_pool_shares_tokens.to_string(),
+                        pool_config.pool_shares_token_decimals,
+                    ),
+                    principal_tokens_withdrawn_normalized: amounts::normalized_amount(
+                        &event.principal_tokens_withdrawn.to_string(),
+                        pool_config.principal_token_decimals,
+                    ),
+                });
+            }
+        }
+        topics::lendergroup_v1::INITIALIZED => {
+            if let Some(event) = abi::lendergroup_contract::events::Initialized::match_and_decode(log) {
+                events.lendergroup_initializeds.push(contract::LendergroupInitialized {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    version: event.version.to_u64(),
+                });
+            }
+        }
+        topics::lendergroup_v1::LENDER_ADDED_PRINCIPAL => {
+            if let Some(event) = abi::lendergroup_contract::events::LenderAddedPrincipal::match_and_decode(log) {
+                events.lendergroup_lender_added_principals.push(contract::LendergroupLenderAddedPrincipal {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    amount: event.amount.to_string(),
+                    lender: event.lender,
+                    shares_amount: event.shares_amount.to_string(),
+                    shares_recipient: event.shares_recipient,
+                    amount_normalized: amounts::normalized_amount(&event.amount.to_string(), pool_config.principal_token_decimals),
+                    shares_amount_normalized: amounts::normalized_amount(&event.shares_amount.to_string(), pool_config.pool_shares_token_decimals),
+                });
+            }
+        }
+        topics::lendergroup_v1::LOAN_REPAID => {
+            if let Some(event) = abi::lendergroup_contract::events::LoanRepaid::match_and_decode(log) {
+                events.lendergroup_loan_repaids.push(contract::LendergroupLoanRepaid {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    bid_id: event.bid_id.to_string(),
+                    interest_amount: event.interest_amount.to_string(),
+                    principal_amount: event.principal_amount.to_string(),
+                    repayer: event.repayer,
+                    total_interest_collected: event.total_interest_collected.to_string(),
+                    total_principal_repaid: event.total_principal_repaid.to_string(),
+                    principal_amount_normalized: amounts::normalized_amount(&event.principal_amount.to_string(), pool_config.principal_token_decimals),
+                    interest_amount_normalized: amounts::normalized_amount(&event.interest_amount.to_string(), pool_config.principal_token_decimals),
+                    total_principal_repaid_normalized: amounts::normalized_amount(
+                        &event.total_principal_repaid.to_string(),
+                        pool_config.principal_token_decimals,
+                    ),
+                    total_interest_collected_normalized: amounts::normalized_amount(
+                        &event.total_interest_collected.to_string(),
+                        pool_config.principal_token_decimals,
+                    ),
+                });
+            }
+        }
+        topics::lendergroup_v1::OWNERSHIP_TRANSFERRED => {
+            if let Some(event) = abi::lendergroup_contract::events::OwnershipTransferred::match_and_decode(log) {
+                events.lendergroup_ownership_transferreds.push(contract::LendergroupOwnershipTransferred {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    new_owner: event.new_owner,
+                    previous_owner: event.previous_owner,
+                });
+            }
+        }
+        topics::lendergroup_v1::PAUSED => {
+            if let Some(event) = abi::lendergroup_contract::events::Paused::match_and_decode(log) {
+                events.lendergroup_pauseds.push(contract::LendergroupPaused {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    account: event.account,
+                });
+            }
+        }
+        topics::lendergroup_v1::POOL_INITIALIZED => {
+            if let Some(event) = abi::lendergroup_contract::events::PoolInitialized::match_and_decode(log) {
+                events.lendergroup_pool_initializeds.push(contract::LendergroupPoolInitialized {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    collateral_token_address: event.collateral_token_address,
+                    interest_rate_lower_bound: event.interest_rate_lower_bound.to_u64(),
+                    interest_rate_upper_bound: event.interest_rate_upper_bound.to_u64(),
+                    liquidity_threshold_percent: event.liquidity_threshold_percent.to_u64(),
+                    loan_to_value_percent: event.loan_to_value_percent.to_u64(),
+                    market_id: event.market_id.to_string(),
+                    max_loan_duration: event.max_loan_duration.to_u64(),
+                    pool_shares_token: event.pool_shares_token,
+                    principal_token_address: event.principal_token_address,
+                    twap_interval: event.twap_interval.to_u64(),
+                    uniswap_pool_fee: event.uniswap_pool_fee.to_u64(),
+                    rpc_enrichment_status: pool_config.rpc_enrichment_status,
+                    teller_v2_address: pool_config.teller_v2_address.clone(),
+                    uniswap_v3_pool_address: pool_config.uniswap_v3_pool_address.clone(),
+                    smart_commitment_forwarder_address: pool_config.smart_commitment_forwarder_address.clone(),
+                });
+            }
+        }
+        topics::lendergroup_v1::UNPAUSED => {
+            if let Some(event) = abi::lendergroup_contract::events::Unpaused::match_and_decode(log) {
+                events.lendergroup_unpauseds.push(contract::LendergroupUnpaused {
+                    evt_tx_hash: Hex(&trx.hash).to_string(),
+                    evt_index: log.block_index,
+                    evt_ordinal: log.ordinal,
+                    evt_block_time: blk.timestamp_seconds(),
+                    evt_block_number: blk.number,
+                    factory_address: factory.address,
+                    factory_version: factory.version,
+                    evt_address: Hex(&log.address).to_string(),
+                    principal_token_address: pool_config.principal_token_address.clone(),
+                    collateral_token_address: pool_config.collateral_token_address.clone(),
+                    account: event.account,
+                });
+            }
+        }
+        _ => {}
+    }
 }
 
 
@@ -392,6 +470,8 @@ fn db_factory_out(events: &contract::Events, tables: &mut DatabaseChangeTables)
             .create_row("factory_admin_changed", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time )
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("new_admin", Hex(&evt.new_admin).to_string())
             .set("previous_admin", Hex(&evt.previous_admin).to_string());
     });
@@ -400,98 +480,200 @@ fn db_factory_out(events: &contract::Events, tables: &mut DatabaseChangeTables)
             .create_row("factory_beacon_upgraded", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("beacon", Hex(&evt.beacon).to_string());
     });
     events.factory_deployed_lender_group_contracts.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("factory_deployed_lender_group_contract", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
-            .set("group_contract", Hex(&evt.group_contract).to_string());
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
+            .set("group_contract", Hex(&evt.group_contract).to_string())
+            .set("deployer", Hex(&evt.deployer).to_string());
+        // requested parameters, left unset when the deploy call could not be matched
+        if let Some(deploy_call) = &evt.deploy_call {
+            row.set("initial_principal_amount", BigDecimal::from_str(&deploy_call.initial_principal_amount).unwrap())
+                .set("principal_token_address", Hex(&deploy_call.principal_token_address).to_string())
+                .set("collateral_token_address", Hex(&deploy_call.collateral_token_address).to_string())
+                .set("market_id", BigDecimal::from_str(&deploy_call.market_id).unwrap())
+                .set("max_loan_duration", deploy_call.max_loan_duration)
+                .set("interest_rate_lower_bound", deploy_call.interest_rate_lower_bound)
+                .set("interest_rate_upper_bound", deploy_call.interest_rate_upper_bound)
+                .set("liquidity_threshold_percent", deploy_call.liquidity_threshold_percent)
+                .set("loan_to_value_percent", deploy_call.loan_to_value_percent)
+                .set("uniswap_pool_fee", deploy_call.uniswap_pool_fee)
+                .set("twap_interval", deploy_call.twap_interval);
+        }
     });
     events.factory_upgradeds.iter().for_each(|evt| {
         tables
             .create_row("factory_upgraded", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("implementation", Hex(&evt.implementation).to_string());
     });
 }
 fn db_lendergroup_out(events: &contract::Events, tables: &mut DatabaseChangeTables) {
     // Loop over all the abis events to create table changes
     events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_borrower_accepted_funds", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
             .set("borrower", Hex(&evt.borrower).to_string())
             .set("collateral_amount", BigDecimal::from_str(&evt.collateral_amount).unwrap())
             .set("interest_rate", evt.interest_rate)
             .set("loan_duration", evt.loan_duration)
             .set("principal_amount", BigDecimal::from_str(&evt.principal_amount).unwrap());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("principal_amount_normalized", &evt.principal_amount_normalized),
+            ("collateral_amount_normalized", &evt.collateral_amount_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
+        if let Some(bid) = &evt.teller_v2_bid {
+            teller_v2::db_bid_details(bid, row);
+        }
+        if let Some(audit) = &evt.collateral_audit {
+            collateral_audits::db_collateral_audit(audit, row);
+        }
     });
     events.lendergroup_defaulted_loan_liquidateds.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_defaulted_loan_liquidated", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("amount_due", BigDecimal::from_str(&evt.amount_due).unwrap())
             .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
             .set("liquidator", Hex(&evt.liquidator).to_string())
             .set("token_amount_difference", BigDecimal::from_str(&evt.token_amount_difference).unwrap());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("amount_due_normalized", &evt.amount_due_normalized),
+            ("token_amount_difference_normalized", &evt.token_amount_difference_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_earnings_withdrawns.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_earnings_withdrawn", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("amount_pool_shares_tokens", BigDecimal::from_str(&evt.amount_pool_shares_tokens).unwrap())
             .set("lender", Hex(&evt.lender).to_string())
             .set("principal_tokens_withdrawn", BigDecimal::from_str(&evt.principal_tokens_withdrawn).unwrap())
             .set("recipient", Hex(&evt.recipient).to_string());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("amount_pool_shares_tokens_normalized", &evt.amount_pool_shares_tokens_normalized),
+            ("principal_tokens_withdrawn_normalized", &evt.principal_tokens_withdrawn_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_initializeds.iter().for_each(|evt| {
         tables
             .create_row("lendergroup_initialized", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("version", evt.version);
     });
     events.lendergroup_lender_added_principals.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_lender_added_principal", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("amount", BigDecimal::from_str(&evt.amount).unwrap())
             .set("lender", Hex(&evt.lender).to_string())
             .set("shares_amount", BigDecimal::from_str(&evt.shares_amount).unwrap())
             .set("shares_recipient", Hex(&evt.shares_recipient).to_string());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("amount_normalized", &evt.amount_normalized),
+            ("shares_amount_normalized", &evt.shares_amount_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_loan_repaids.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_loan_repaid", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
             .set("interest_amount", BigDecimal::from_str(&evt.interest_amount).unwrap())
             .set("principal_amount", BigDecimal::from_str(&evt.principal_amount).unwrap())
             .set("repayer", Hex(&evt.repayer).to_string())
             .set("total_interest_collected", BigDecimal::from_str(&evt.total_interest_collected).unwrap())
             .set("total_principal_repaid", BigDecimal::from_str(&evt.total_principal_repaid).unwrap());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("principal_amount_normalized", &evt.principal_amount_normalized),
+            ("interest_amount_normalized", &evt.interest_amount_normalized),
+            ("total_principal_repaid_normalized", &evt.total_principal_repaid_normalized),
+            ("total_interest_collected_normalized", &evt.total_interest_collected_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_ownership_transferreds.iter().for_each(|evt| {
         tables
             .create_row("lendergroup_ownership_transferred", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("new_owner", Hex(&evt.new_owner).to_string())
             .set("previous_owner", Hex(&evt.previous_owner).to_string());
     });
@@ -500,14 +682,20 @@ fn db_lendergroup_out(events: &contract::Events, tables: &mut DatabaseChangeTabl
             .create_row("lendergroup_paused", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("account", Hex(&evt.account).to_string());
     });
     events.lendergroup_pool_initializeds.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_pool_initialized", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
             .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("interest_rate_lower_bound", evt.interest_rate_lower_bound)
@@ -519,14 +707,29 @@ fn db_lendergroup_out(events: &contract::Events, tables: &mut DatabaseChangeTabl
             .set("pool_shares_token", Hex(&evt.pool_shares_token).to_string())
             .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
             .set("twap_interval", evt.twap_interval)
-            .set("uniswap_pool_fee", evt.uniswap_pool_fee);
+            .set("uniswap_pool_fee", evt.uniswap_pool_fee)
+            .set("rpc_enrichment_status", rpc_failures::status_label(evt.rpc_enrichment_status()).to_string());
+        // RPC fields are left unset when their eth_call failed
+        for (column, value) in [
+            ("uniswap_v3_pool_address", &evt.uniswap_v3_pool_address),
+            ("teller_v2_address", &evt.teller_v2_address),
+            ("smart_commitment_forwarder_address", &evt.smart_commitment_forwarder_address),
+        ] {
+            if let Some(address) = value {
+                row.set(column, Hex(address).to_string());
+            }
+        }
     });
     events.lendergroup_unpauseds.iter().for_each(|evt| {
         tables
             .create_row("lendergroup_unpaused", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("account", Hex(&evt.account).to_string());
     });
 }
@@ -541,6 +744,8 @@ fn graph_factory_out(events: &contract::Events, tables: &mut EntityChangesTables
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("new_admin", Hex(&evt.new_admin).to_string())
             .set("previous_admin", Hex(&evt.previous_admin).to_string());
     });
@@ -551,16 +756,35 @@ fn graph_factory_out(events: &contract::Events, tables: &mut EntityChangesTables
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("beacon", Hex(&evt.beacon).to_string());
     });
     events.factory_deployed_lender_group_contracts.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("factory_deployed_lender_group_contract", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
-            .set("group_contract", Hex(&evt.group_contract).to_string());
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
+            .set("group_contract", Hex(&evt.group_contract).to_string())
+            .set("deployer", Hex(&evt.deployer).to_string());
+        // requested parameters, left null when the deploy call could not be matched
+        if let Some(deploy_call) = &evt.deploy_call {
+            row.set("initial_principal_amount", BigDecimal::from_str(&deploy_call.initial_principal_amount).unwrap())
+                .set("principal_token_address", Hex(&deploy_call.principal_token_address).to_string())
+                .set("collateral_token_address", Hex(&deploy_call.collateral_token_address).to_string())
+                .set("market_id", BigDecimal::from_str(&deploy_call.market_id).unwrap())
+                .set("max_loan_duration", deploy_call.max_loan_duration)
+                .set("interest_rate_lower_bound", deploy_call.interest_rate_lower_bound)
+                .set("interest_rate_upper_bound", deploy_call.interest_rate_upper_bound)
+                .set("liquidity_threshold_percent", deploy_call.liquidity_threshold_percent)
+                .set("loan_to_value_percent", deploy_call.loan_to_value_percent)
+                .set("uniswap_pool_fee", deploy_call.uniswap_pool_fee)
+                .set("twap_interval", deploy_call.twap_interval);
+        }
     });
     events.factory_upgradeds.iter().for_each(|evt| {
         tables
@@ -569,51 +793,98 @@ fn graph_factory_out(events: &contract::Events, tables: &mut EntityChangesTables
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("implementation", Hex(&evt.implementation).to_string());
     });
 }
 fn graph_lendergroup_out(events: &contract::Events, tables: &mut EntityChangesTables) {
     // Loop over all the abis events to create table changes
     events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_borrower_accepted_funds", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
             .set("borrower", Hex(&evt.borrower).to_string())
             .set("collateral_amount", BigDecimal::from_str(&evt.collateral_amount).unwrap())
             .set("interest_rate", evt.interest_rate)
             .set("loan_duration", evt.loan_duration)
             .set("principal_amount", BigDecimal::from_str(&evt.principal_amount).unwrap());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("principal_amount_normalized", &evt.principal_amount_normalized),
+            ("collateral_amount_normalized", &evt.collateral_amount_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
+        if let Some(bid) = &evt.teller_v2_bid {
+            teller_v2::graph_bid_details(bid, row);
+        }
+        if let Some(audit) = &evt.collateral_audit {
+            collateral_audits::graph_collateral_audit(audit, row);
+        }
     });
     events.lendergroup_defaulted_loan_liquidateds.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_defaulted_loan_liquidated", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("amount_due", BigDecimal::from_str(&evt.amount_due).unwrap())
             .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
             .set("liquidator", Hex(&evt.liquidator).to_string())
             .set("token_amount_difference", BigDecimal::from_str(&evt.token_amount_difference).unwrap());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("amount_due_normalized", &evt.amount_due_normalized),
+            ("token_amount_difference_normalized", &evt.token_amount_difference_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_earnings_withdrawns.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_earnings_withdrawn", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("amount_pool_shares_tokens", BigDecimal::from_str(&evt.amount_pool_shares_tokens).unwrap())
             .set("lender", Hex(&evt.lender).to_string())
             .set("principal_tokens_withdrawn", BigDecimal::from_str(&evt.principal_tokens_withdrawn).unwrap())
             .set("recipient", Hex(&evt.recipient).to_string());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("amount_pool_shares_tokens_normalized", &evt.amount_pool_shares_tokens_normalized),
+            ("principal_tokens_withdrawn_normalized", &evt.principal_tokens_withdrawn_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_initializeds.iter().for_each(|evt| {
         tables
@@ -622,36 +893,68 @@ fn graph_lendergroup_out(events: &contract::Events, tables: &mut EntityChangesTa
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("version", evt.version);
     });
     events.lendergroup_lender_added_principals.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_lender_added_principal", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("amount", BigDecimal::from_str(&evt.amount).unwrap())
             .set("lender", Hex(&evt.lender).to_string())
             .set("shares_amount", BigDecimal::from_str(&evt.shares_amount).unwrap())
             .set("shares_recipient", Hex(&evt.shares_recipient).to_string());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("amount_normalized", &evt.amount_normalized),
+            ("shares_amount_normalized", &evt.shares_amount_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_loan_repaids.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_loan_repaid", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
             .set("interest_amount", BigDecimal::from_str(&evt.interest_amount).unwrap())
             .set("principal_amount", BigDecimal::from_str(&evt.principal_amount).unwrap())
             .set("repayer", Hex(&evt.repayer).to_string())
             .set("total_interest_collected", BigDecimal::from_str(&evt.total_interest_collected).unwrap())
             .set("total_principal_repaid", BigDecimal::from_str(&evt.total_principal_repaid).unwrap());
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("principal_amount_normalized", &evt.principal_amount_normalized),
+            ("interest_amount_normalized", &evt.interest_amount_normalized),
+            ("total_principal_repaid_normalized", &evt.total_principal_repaid_normalized),
+            ("total_interest_collected_normalized", &evt.total_interest_collected_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
     });
     events.lendergroup_ownership_transferreds.iter().for_each(|evt| {
         tables
@@ -660,7 +963,11 @@ fn graph_lendergroup_out(events: &contract::Events, tables: &mut EntityChangesTa
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("new_owner", Hex(&evt.new_owner).to_string())
             .set("previous_owner", Hex(&evt.previous_owner).to_string());
     });
@@ -671,16 +978,22 @@ fn graph_lendergroup_out(events: &contract::Events, tables: &mut EntityChangesTa
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("account", Hex(&evt.account).to_string());
     });
     events.lendergroup_pool_initializeds.iter().for_each(|evt| {
-        tables
+        let row = tables
             .create_row("lendergroup_pool_initialized", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
             .set("evt_tx_hash", &evt.evt_tx_hash)
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
             .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("interest_rate_lower_bound", evt.interest_rate_lower_bound)
@@ -692,7 +1005,18 @@ fn graph_lendergroup_out(events: &contract::Events, tables: &mut EntityChangesTa
             .set("pool_shares_token", Hex(&evt.pool_shares_token).to_string())
             .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
             .set("twap_interval", evt.twap_interval)
-            .set("uniswap_pool_fee", evt.uniswap_pool_fee);
+            .set("uniswap_pool_fee", evt.uniswap_pool_fee)
+            .set("rpc_enrichment_status", rpc_failures::status_label(evt.rpc_enrichment_status()).to_string());
+        // RPC fields are left unset when their eth_call failed
+        for (column, value) in [
+            ("uniswap_v3_pool_address", &evt.uniswap_v3_pool_address),
+            ("teller_v2_address", &evt.teller_v2_address),
+            ("smart_commitment_forwarder_address", &evt.smart_commitment_forwarder_address),
+        ] {
+            if let Some(address) = value {
+                row.set(column, Hex(address).to_string());
+            }
+        }
     });
     events.lendergroup_unpauseds.iter().for_each(|evt| {
         tables
@@ -701,21 +1025,91 @@ fn graph_lendergroup_out(events: &contract::Events, tables: &mut EntityChangesTa
             .set("evt_index", evt.evt_index)
             .set("evt_block_time", evt.evt_block_time)
             .set("evt_block_number", evt.evt_block_number)
+            .set("factory_address", Hex(&evt.factory_address).to_string())
+            .set("factory_version", factories::version_label(evt.factory_version()).to_string())
             .set("evt_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
             .set("account", Hex(&evt.account).to_string());
     });
 }
+#[substreams::handlers::map]
+fn map_tracked_factories(
+    params: String,
+    clock: substreams::pb::substreams::Clock,
+) -> Result<contract::TrackedFactories, substreams::errors::Error> {
+    let factories = factories::parse_tracked_factories(&params)?
+        .into_iter()
+        .filter(|factory| clock.number >= factory.start_block)
+        .collect();
+
+    Ok(contract::TrackedFactories { factories })
+}
+
 #[substreams::handlers::store]
-fn store_factory_lendergroup_created(blk: eth::Block, store: StoreSetInt64) {
+fn store_factory_lendergroup_created(
+    blk: eth::Block,
+    tracked_factories: contract::TrackedFactories,
+    store: StoreSetProto<contract::TrackedFactory>,
+) {
     for rcpt in blk.receipts() {
-        for log in rcpt
-            .receipt
-            .logs
-            .iter()
-            .filter(|log| log.address == FACTORY_TRACKED_CONTRACT)
-        {
+        for log in rcpt.receipt.logs.iter() {
+            let Some(factory) = tracked_factories
+                .factories
+                .iter()
+                .find(|factory| factory.address == log.address) else { continue };
+
             if let Some(event) = abi::factory_contract::events::DeployedLenderGroupContract::match_and_decode(log) {
-                store.set(log.ordinal, Hex(event.group_contract).to_string(), &1);
+                // set at the deploy log's ordinal so pool logs later in the same block (initialize) already see it
+                store.set(log.ordinal, dds_store_key(&event.group_contract), factory);
+            }
+        }
+    }
+}
+
+#[substreams::handlers::store]
+fn store_pool_configs(
+    blk: eth::Block,
+    store_lendergroup: StoreGetProto<contract::TrackedFactory>,
+    store: StoreSetProto<contract::PoolConfig>,
+) {
+    let mut initialized: Vec<(u64, contract::PoolConfig)> = vec![];
+    for rcpt in blk.receipts() {
+        for log in rcpt.receipt.logs.iter() {
+            // decode first, only PoolInitialized logs are looked up in the pool store
+            let pool_config = abi::lendergroup_contract::events::PoolInitialized::match_and_decode(log).and_then(|event| {
+                let factory = declared_dds_factory(&log.address, log.ordinal, &store_lendergroup)?;
+                Some(pool_configs::v1_pool_config(&log.address, &factory, event))
+            });
+            if let Some(pool_config) = pool_config {
+                initialized.push((log.ordinal, pool_config));
+            }
+        }
+    }
+
+    // one RpcBatch for every pool initialized in the block, a failed eth_call leaves its field unset (see map_rpc_failures)
+    let mut pool_configs: Vec<contract::PoolConfig> = initialized.iter().map(|(_, pool_config)| pool_config.clone()).collect();
+    rpc::enrich_pool_configs(&mut pool_configs);
+
+    for ((ordinal, _), pool_config) in initialized.iter().zip(pool_configs) {
+        store.set(*ordinal, pool_config.group_pool_address.clone(), &pool_config);
+    }
+}
+
+#[substreams::handlers::store]
+fn store_share_tokens(
+    blk: eth::Block,
+    store_lendergroup: StoreGetProto<contract::TrackedFactory>,
+    store: StoreSetString,
+) {
+    for rcpt in blk.receipts() {
+        for log in rcpt.receipt.logs.iter() {
+            let pool_shares_token = abi::lendergroup_contract::events::PoolInitialized::match_and_decode(log)
+                .filter(|_| declared_dds_factory(&log.address, log.ordinal, &store_lendergroup).is_some())
+                .map(|event| event.pool_shares_token);
+            if let Some(pool_shares_token) = pool_shares_token {
+                // the shares token becomes a data source of its pool, its Transfer logs are decoded by map_events
+                store.set(log.ordinal, shares::share_token_key(&pool_shares_token), &Hex(&log.address).to_string());
             }
         }
     }
@@ -724,28 +1118,384 @@ fn store_factory_lendergroup_created(blk: eth::Block, store: StoreSetInt64) {
 #[substreams::handlers::map]
 fn map_events(
     blk: eth::Block,
-    store_lendergroup: StoreGetInt64,
+    tracked_factories: contract::TrackedFactories,
+    store_lendergroup: StoreGetProto<contract::TrackedFactory>,
+    store_pool_configs: StoreGetProto<contract::PoolConfig>,
+    store_share_tokens: StoreGetString,
 ) -> Result<contract::Events, substreams::errors::Error> {
     let mut events = contract::Events::default();
-    map_factory_events(&blk, &mut events);
-    map_lendergroup_events(&blk, &store_lendergroup, &mut events);
+    map_v1_events(&blk, &tracked_factories, &store_lendergroup, &store_pool_configs, &store_share_tokens, &mut events);
+    // one RpcBatch on the TellerV2 contracts for every loan accepted in the block (see map_rpc_failures)
+    teller_v2::enrich_accepted_bids(&mut events.lendergroup_borrower_accepted_funds, &store_pool_configs);
+    // and on the pools themselves, for the oracle price and required collateral of those loans
+    collateral_audits::enrich_collateral_audits(&blk, &mut events.lendergroup_borrower_accepted_funds, &store_pool_configs);
     Ok(events)
 }
 
 #[substreams::handlers::map]
-fn db_out(events: contract::Events) -> Result<DatabaseChanges, substreams::errors::Error> {
+fn map_calls(
+    blk: eth::Block,
+    store_lendergroup: StoreGetProto<contract::TrackedFactory>,
+) -> Result<contract::Calls, substreams::errors::Error> {
+    let mut calls = contract::Calls::default();
+    map_v1_calls(&blk, &store_lendergroup, &mut calls);
+    Ok(calls)
+}
+
+#[substreams::handlers::map]
+fn map_reverted_calls(calls: contract::Calls) -> Result<contract::RevertedCalls, substreams::errors::Error> {
+    Ok(contract::RevertedCalls {
+        calls: calls.reverted_calls,
+    })
+}
+
+#[substreams::handlers::store]
+fn store_teller_v2_bids(
+    events: contract::Events,
+    store_pool_configs: StoreGetProto<contract::PoolConfig>,
+    store: StoreSetString,
+) {
+    teller_v2::set_teller_v2_bids(&events, &store_pool_configs, &store);
+}
+
+#[substreams::handlers::store]
+fn store_teller_v2_submitted_bids(
+    blk: eth::Block,
+    store_teller_v2_bids: StoreGetString,
+    store: StoreSetProto<contract::TellerV2SubmittedBid>,
+) {
+    teller_v2::set_teller_v2_submitted_bids(&blk, &store_teller_v2_bids, &store);
+}
+
+#[substreams::handlers::map]
+fn map_teller_v2_events(
+    blk: eth::Block,
+    store_teller_v2_bids: StoreGetString,
+    store_teller_v2_submitted_bids: StoreGetProto<contract::TellerV2SubmittedBid>,
+) -> Result<contract::TellerV2Events, substreams::errors::Error> {
+    Ok(teller_v2::map_teller_v2_events(&blk, &store_teller_v2_bids, &store_teller_v2_submitted_bids))
+}
+
+#[substreams::handlers::store]
+fn store_uniswap_pools(events: contract::Events, store: StoreAppend<String>) {
+    collateral_prices::append_uniswap_pools(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_collateral_prices(
+    blk: eth::Block,
+    store_uniswap_pools: StoreGetArray<String>,
+    store_pool_configs: StoreGetProto<contract::PoolConfig>,
+) -> Result<contract::CollateralPrices, substreams::errors::Error> {
+    Ok(collateral_prices::map_collateral_prices(&blk, &store_uniswap_pools, &store_pool_configs))
+}
+
+#[substreams::handlers::store]
+fn store_collateral_prices(prices: contract::CollateralPrices, store: StoreSetProto<contract::CollateralPrice>) {
+    collateral_prices::set_latest_collateral_prices(&prices, &store);
+}
+
+#[substreams::handlers::store]
+fn store_pool_totals(events: contract::Events, store: StoreAddBigInt) {
+    pool_metrics::add_pool_totals(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_lender_totals(events: contract::Events, store: StoreAddBigInt) {
+    lender_metrics::add_lender_totals(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_share_balances(events: contract::Events, store: StoreAddBigInt) {
+    shares::add_share_balances(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_share_exchange_rates(events: contract::Events) -> Result<contract::ShareExchangeRates, substreams::errors::Error> {
+    Ok(exchange_rates::map_share_exchange_rates(&events))
+}
+
+#[substreams::handlers::store]
+fn store_share_exchange_rates(
+    rates: contract::ShareExchangeRates,
+    store: StoreSetProto<contract::ShareExchangeRate>,
+) {
+    exchange_rates::set_latest_share_exchange_rates(&rates, &store);
+}
+
+#[substreams::handlers::store]
+fn store_share_exchange_rate_opens(
+    rates: contract::ShareExchangeRates,
+    latest_rates: Deltas<DeltaProto<contract::ShareExchangeRate>>,
+    store: StoreSetIfNotExistsBigDecimal,
+) {
+    exchange_rates::delete_previous_candles(&latest_rates, &store);
+    exchange_rates::set_candle_opens(&rates, &store);
+}
+
+#[substreams::handlers::store]
+fn store_share_exchange_rate_highs(
+    rates: contract::ShareExchangeRates,
+    latest_rates: Deltas<DeltaProto<contract::ShareExchangeRate>>,
+    store: StoreMaxBigDecimal,
+) {
+    exchange_rates::delete_previous_candles(&latest_rates, &store);
+    exchange_rates::max_candle_highs(&rates, &store);
+}
+
+#[substreams::handlers::store]
+fn store_share_exchange_rate_lows(
+    rates: contract::ShareExchangeRates,
+    latest_rates: Deltas<DeltaProto<contract::ShareExchangeRate>>,
+    store: StoreMinBigDecimal,
+) {
+    exchange_rates::delete_previous_candles(&latest_rates, &store);
+    exchange_rates::min_candle_lows(&rates, &store);
+}
+
+#[substreams::handlers::map]
+fn map_share_exchange_rate_candles(
+    clock: substreams::pb::substreams::Clock,
+    rates: contract::ShareExchangeRates,
+    opens: StoreGetBigDecimal,
+    highs: StoreGetBigDecimal,
+    lows: StoreGetBigDecimal,
+) -> Result<contract::ShareExchangeRateCandles, substreams::errors::Error> {
+    let block_time = clock.timestamp.as_ref().map(|ts| ts.seconds as u64).unwrap_or_default();
+    Ok(exchange_rates::map_share_exchange_rate_candles(clock.number, block_time, &rates, &opens, &highs, &lows))
+}
+
+#[substreams::handlers::map]
+fn map_pool_metrics_data_points(
+    clock: substreams::pb::substreams::Clock,
+    events: contract::Events,
+    pool_totals: StoreGetBigInt,
+) -> Result<contract::PoolMetricsDataPoints, substreams::errors::Error> {
+    let block_time = clock.timestamp.as_ref().map(|ts| ts.seconds as u64).unwrap_or_default();
+    Ok(data_points::map_pool_metrics_data_points(clock.number, block_time, &events, &pool_totals))
+}
+
+#[substreams::handlers::store]
+fn store_pool_metrics_data_point_periods(pool_metrics_data_points: contract::PoolMetricsDataPoints, store: StoreSetInt64) {
+    data_points::set_data_point_periods(&pool_metrics_data_points, &store);
+}
+
+#[substreams::handlers::store]
+fn store_loan_originations(events: contract::Events, store: StoreSetProto<contract::Loan>) {
+    loans::set_loan_originations(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_loan_liquidations(events: contract::Events, store: StoreSetProto<contract::LendergroupDefaultedLoanLiquidated>) {
+    loans::set_loan_liquidations(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_loan_repayments(events: contract::Events, store: StoreAddBigInt) {
+    loans::add_loan_repayments(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_loans(
+    events: contract::Events,
+    store_loan_originations: StoreGetProto<contract::Loan>,
+    store_loan_repayments: StoreGetBigInt,
+    store_loan_liquidations: StoreGetProto<contract::LendergroupDefaultedLoanLiquidated>,
+    store_pool_configs: StoreGetProto<contract::PoolConfig>,
+) -> Result<contract::Loans, substreams::errors::Error> {
+    Ok(loans::map_loans(
+        &events,
+        &store_loan_originations,
+        &store_loan_liquidations,
+        &store_loan_repayments,
+        &store_pool_configs,
+    ))
+}
+
+#[substreams::handlers::store]
+fn store_pool_loans(events: contract::Events, store: StoreAppend<String>) {
+    loan_health::append_pool_loans(&events, &store);
+}
+
+#[substreams::handlers::store]
+fn store_loans(loans: contract::Loans, store: StoreSetProto<contract::Loan>) {
+    loan_health::set_latest_loans(&loans, &store);
+}
+
+#[substreams::handlers::store]
+fn store_active_loans(
+    loans: contract::Loans,
+    store_pool_loans: StoreGetArray<String>,
+    store_loans: StoreGetProto<contract::Loan>,
+    store: StoreSetProto<contract::PoolActiveLoans>,
+) {
+    loan_health::set_active_loans(&loans, &store_pool_loans, &store_loans, &store);
+}
+
+#[substreams::handlers::map]
+fn map_loan_health(
+    params: String,
+    clock: substreams::pb::substreams::Clock,
+    loans: contract::Loans,
+    collateral_prices: contract::CollateralPrices,
+    store_active_loans: StoreGetProto<contract::PoolActiveLoans>,
+    store_loans: StoreGetProto<contract::Loan>,
+    store_collateral_prices: StoreGetProto<contract::CollateralPrice>,
+    store_pool_configs: StoreGetProto<contract::PoolConfig>,
+) -> Result<contract::LoanHealths, substreams::errors::Error> {
+    let warning_thresholds = loan_health::parse_warning_thresholds(&params)?;
+    let block_time = clock.timestamp.as_ref().map(|ts| ts.seconds as u64).unwrap_or_default();
+    Ok(loan_health::map_loan_health(
+        &warning_thresholds,
+        clock.number,
+        block_time,
+        &loans,
+        &collateral_prices,
+        &store_active_loans,
+        &store_loans,
+        &store_collateral_prices,
+        &store_pool_configs,
+    ))
+}
+
+#[substreams::handlers::store]
+fn store_loan_warning_levels(healths: contract::LoanHealths, store: StoreSetInt64) {
+    loan_health::set_warning_levels(&healths, &store);
+}
+
+#[substreams::handlers::map]
+fn map_loan_health_alerts(
+    healths: contract::LoanHealths,
+    warning_levels_deltas: Deltas<DeltaInt64>,
+) -> Result<contract::LoanHealthAlerts, substreams::errors::Error> {
+    Ok(loan_health::map_loan_health_alerts(&healths, &warning_levels_deltas))
+}
+
+#[substreams::handlers::store]
+fn store_pools(store_pool_configs_deltas: Deltas<DeltaProto<contract::PoolConfig>>, store: StoreAppend<String>) {
+    snapshots::append_pools(&store_pool_configs_deltas, &store);
+}
+
+#[substreams::handlers::map]
+fn map_pool_onchain_snapshots(
+    params: String,
+    clock: substreams::pb::substreams::Clock,
+    events: contract::Events,
+    store_pools: StoreGetArray<String>,
+) -> Result<contract::PoolOnchainSnapshots, substreams::errors::Error> {
+    let snapshot_interval = snapshots::parse_snapshot_interval(&params)?;
+    let block_time = clock.timestamp.as_ref().map(|ts| ts.seconds as u64).unwrap_or_default();
+    Ok(snapshots::map_pool_onchain_snapshots(snapshot_interval, clock.number, block_time, &events, &store_pools))
+}
+
+#[substreams::handlers::map]
+fn map_reconciliation_mismatches(
+    pool_onchain_snapshots: contract::PoolOnchainSnapshots,
+    pool_totals: StoreGetBigInt,
+) -> Result<contract::ReconciliationMismatches, substreams::errors::Error> {
+    Ok(reconciliation::map_reconciliation_mismatches(&pool_onchain_snapshots, &pool_totals))
+}
+
+#[substreams::handlers::map]
+fn map_rpc_failures(events: contract::Events) -> Result<contract::RpcFailures, substreams::errors::Error> {
+    Ok(rpc_failures::map_rpc_failures(&events))
+}
+
+#[substreams::handlers::store]
+fn store_token_addresses(events: contract::Events, store: StoreSetIfNotExistsString) {
+    tokens::set_token_addresses(&events, &store);
+}
+
+#[substreams::handlers::map]
+fn map_tokens(
+    clock: substreams::pb::substreams::Clock,
+    token_addresses_deltas: Deltas<DeltaString>,
+    store_pool_configs: StoreGetProto<contract::PoolConfig>,
+) -> Result<contract::Tokens, substreams::errors::Error> {
+    Ok(tokens::map_tokens(clock.number, &token_addresses_deltas, &store_pool_configs))
+}
+
+#[substreams::handlers::map]
+fn db_out(
+    events: contract::Events,
+    pool_totals_deltas: Deltas<DeltaBigInt>,
+    lender_totals_deltas: Deltas<DeltaBigInt>,
+    pool_metrics_data_points: contract::PoolMetricsDataPoints,
+    loans: contract::Loans,
+    tokens: contract::Tokens,
+    share_balances_deltas: Deltas<DeltaBigInt>,
+    share_exchange_rates: contract::ShareExchangeRates,
+    share_exchange_rate_candles: contract::ShareExchangeRateCandles,
+    pool_onchain_snapshots: contract::PoolOnchainSnapshots,
+    reconciliation_mismatches: contract::ReconciliationMismatches,
+    teller_v2_events: contract::TellerV2Events,
+    calls: contract::Calls,
+    reverted_calls: contract::RevertedCalls,
+    collateral_prices: contract::CollateralPrices,
+    loan_healths: contract::LoanHealths,
+    loan_health_alerts: contract::LoanHealthAlerts,
+) -> Result<DatabaseChanges, substreams::errors::Error> {
     // Initialize Database Changes container
     let mut tables = DatabaseChangeTables::new();
     db_factory_out(&events, &mut tables);
     db_lendergroup_out(&events, &mut tables);
+    pool_metrics::db_pool_metrics_out(&events, &pool_totals_deltas, &mut tables);
+    lender_metrics::db_lender_metrics_out(&lender_totals_deltas, &mut tables);
+    data_points::db_data_points_out(&pool_metrics_data_points, &mut tables);
+    loans::db_loans_out(&loans, &mut tables);
+    tokens::db_tokens_out(&tokens, &mut tables);
+    shares::db_shares_out(&events, &share_balances_deltas, &mut tables);
+    exchange_rates::db_share_exchange_rates_out(&share_exchange_rates, &share_exchange_rate_candles, &mut tables);
+    snapshots::db_pool_onchain_snapshots_out(&pool_onchain_snapshots, &mut tables);
+    reconciliation::db_reconciliation_mismatches_out(&reconciliation_mismatches, &mut tables);
+    teller_v2::db_teller_v2_out(&teller_v2_events, &mut tables);
+    calls::db_calls_out(&calls, &mut tables);
+    reverted_calls::db_reverted_calls_out(&reverted_calls, &mut tables);
+    collateral_prices::db_collateral_prices_out(&collateral_prices, &mut tables);
+    loan_health::db_loan_health_out(&loan_healths, &loan_health_alerts, &mut tables);
     Ok(tables.to_database_changes())
 }
 
 #[substreams::handlers::map]
-fn graph_out(events: contract::Events) -> Result<EntityChanges, substreams::errors::Error> {
+fn graph_out(
+    events: contract::Events,
+    pool_totals_deltas: Deltas<DeltaBigInt>,
+    lender_totals_deltas: Deltas<DeltaBigInt>,
+    pool_metrics_data_points: contract::PoolMetricsDataPoints,
+    data_point_periods_deltas: Deltas<DeltaInt64>,
+    loans: contract::Loans,
+    tokens: contract::Tokens,
+    share_balances_deltas: Deltas<DeltaBigInt>,
+    share_exchange_rates: contract::ShareExchangeRates,
+    share_exchange_rate_candles: contract::ShareExchangeRateCandles,
+    share_exchange_rate_opens_deltas: Deltas<DeltaBigDecimal>,
+    pool_onchain_snapshots: contract::PoolOnchainSnapshots,
+    reconciliation_mismatches: contract::ReconciliationMismatches,
+    teller_v2_events: contract::TellerV2Events,
+    collateral_prices: contract::CollateralPrices,
+    loan_healths: contract::LoanHealths,
+    loan_health_alerts: contract::LoanHealthAlerts,
+) -> Result<EntityChanges, substreams::errors::Error> {
     // Initialize Database Changes container
     let mut tables = EntityChangesTables::new();
     graph_factory_out(&events, &mut tables);
     graph_lendergroup_out(&events, &mut tables);
+    pool_metrics::graph_pool_metrics_out(&events, &pool_totals_deltas, &mut tables);
+    lender_metrics::graph_lender_metrics_out(&lender_totals_deltas, &mut tables);
+    data_points::graph_data_points_out(&pool_metrics_data_points, &data_point_periods_deltas, &mut tables);
+    loans::graph_loans_out(&loans, &mut tables);
+    tokens::graph_tokens_out(&tokens, &mut tables);
+    shares::graph_shares_out(&events, &share_balances_deltas, &mut tables);
+    exchange_rates::graph_share_exchange_rates_out(
+        &share_exchange_rates,
+        &share_exchange_rate_candles,
+        &share_exchange_rate_opens_deltas,
+        &mut tables,
+    );
+    snapshots::graph_pool_onchain_snapshots_out(&pool_onchain_snapshots, &mut tables);
+    reconciliation::graph_reconciliation_mismatches_out(&reconciliation_mismatches, &mut tables);
+    teller_v2::graph_teller_v2_out(&teller_v2_events, &mut tables);
+    collateral_prices::graph_collateral_prices_out(&collateral_prices, &mut tables);
+    loan_health::graph_loan_health_out(&loan_healths, &loan_health_alerts, &mut tables);
     Ok(tables.to_entity_changes())
 }
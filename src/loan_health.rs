@@ -0,0 +1,492 @@
+use crate::loans;
+use crate::pb::contract::v1 as contract;
+use contract::LoanStatus;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use substreams::errors::Error;
+use substreams::key;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{
+    DeltaInt64, Deltas, StoreAppend, StoreDelete, StoreGet, StoreGetArray, StoreGetProto, StoreSet, StoreSetInt64,
+    StoreSetProto,
+};
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+/*
+
+`map_loan_health` params: comma separated LTV warning thresholds in the pool's percent units
+(10000 = 100%), e.g. `8000,9000,10000`. The warning_level of a loan is the number of thresholds
+its LTV reaches, and map_loan_health_alerts emits every change of that level. Empty for none.
+
+*/
+
+// 10000 = 100%, the unit of the pool collateral ratio (loan_to_value_percent)
+const PERCENT_FACTOR: u64 = 10000;
+
+// pool_loans:{group pool address} holding the bid id of every loan accepted by the pool
+pub fn pool_loans_key(pool_address: &str) -> String {
+    format!("pool_loans:{}", pool_address)
+}
+
+// active_loans:{group pool address} holding the bid ids of the pool loans that are not repaid or liquidated yet
+pub fn active_loans_key(pool_address: &str) -> String {
+    format!("active_loans:{}", pool_address)
+}
+
+pub fn parse_warning_thresholds(params: &str) -> Result<Vec<u64>, Error> {
+    let mut thresholds = params
+        .split(',')
+        .map(|threshold| threshold.trim())
+        .filter(|threshold| !threshold.is_empty())
+        .map(|threshold| {
+            threshold
+                .parse::<u64>()
+                .map_err(|e| Error::msg(format!("invalid LTV warning threshold `{}` in params: {}", threshold, e)))
+        })
+        .collect::<Result<Vec<u64>, Error>>()?;
+    thresholds.sort_unstable();
+    Ok(thresholds)
+}
+
+pub fn append_pool_loans(events: &contract::Events, store: &StoreAppend<String>) {
+    events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
+        store.append(evt.evt_ordinal, pool_loans_key(&evt.evt_address), evt.bid_id.clone());
+    });
+}
+
+// latest state of every loan touched by map_loans, keyed like store_loan_originations
+pub fn set_latest_loans(loans: &contract::Loans, store: &StoreSetProto<contract::Loan>) {
+    loans.loans.iter().for_each(|loan| {
+        store.set(loan.updated_ordinal, loans::loan_key(&loan.group_pool_address, &loan.bid_id), loan);
+    });
+}
+
+// the pool's loan history is only walked in blocks where one of its loans changed, so a Swap on the
+// oracle pool reads the active loans alone. The key is deleted once the last active loan closes
+pub fn set_active_loans(
+    loans: &contract::Loans,
+    pool_loans: &StoreGetArray<String>,
+    latest_loans: &StoreGetProto<contract::Loan>,
+    store: &StoreSetProto<contract::PoolActiveLoans>,
+) {
+    let mut changed_pools: BTreeMap<&str, u64> = BTreeMap::new();
+    loans.loans.iter().for_each(|loan| {
+        let ordinal = changed_pools.entry(loan.group_pool_address.as_str()).or_default();
+        *ordinal = (*ordinal).max(loan.updated_ordinal);
+    });
+
+    for (pool_address, ordinal) in changed_pools {
+        let bid_ids: Vec<String> = pool_loans
+            .get_last(pool_loans_key(pool_address))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|bid_id| {
+                latest_loans
+                    .get_last(loans::loan_key(pool_address, bid_id))
+                    .map_or(false, |loan| loan.status() == LoanStatus::Active)
+            })
+            .collect();
+        if bid_ids.is_empty() {
+            store.delete_prefix(ordinal as i64, &active_loans_key(pool_address));
+        } else {
+            store.set(
+                ordinal,
+                active_loans_key(pool_address),
+                &contract::PoolActiveLoans {
+                    group_pool_address: pool_address.to_string(),
+                    bid_ids,
+                },
+            );
+        }
+    }
+}
+
+fn percent(value: u64) -> BigDecimal {
+    BigInt::from(value).to_decimal(0)
+}
+
+fn loan_health(
+    loan: &contract::Loan,
+    price: Option<&contract::CollateralPrice>,
+    pool_config: &contract::PoolConfig,
+    warning_thresholds: &[u64],
+    block_number: u64,
+    block_time: u64,
+    ordinal: u64,
+) -> contract::LoanHealth {
+    let principal_amount = BigInt::from_str(&loan.principal_amount).unwrap();
+    let principal_repaid = BigInt::from_str(&loan.principal_repaid).unwrap();
+    let outstanding_principal = if principal_repaid >= principal_amount {
+        BigInt::zero()
+    } else {
+        principal_amount - principal_repaid
+    };
+
+    // price is collateral per principal base unit, so the collateral is worth collateral_amount / price principal
+    let collateral_amount = BigInt::from_str(&loan.collateral_amount).unwrap();
+    let collateral_value = price
+        .map(|price| BigDecimal::from_str(&price.price).unwrap())
+        .filter(|price| *price != BigDecimal::zero() && collateral_amount != BigInt::zero())
+        .map(|price| collateral_amount.to_decimal(0) / price);
+    let ltv_percent = collateral_value
+        .as_ref()
+        .map(|collateral_value| outstanding_principal.to_decimal(0) * percent(PERCENT_FACTOR) / collateral_value.clone());
+
+    // loan_to_value_percent is the collateral required per unit of principal, so a ratio of 15000 allows an LTV of 6666
+    let collateral_ratio = pool_config.loan_to_value_percent;
+    let max_ltv_percent = (collateral_ratio > 0)
+        .then(|| percent(PERCENT_FACTOR) * percent(PERCENT_FACTOR) / percent(collateral_ratio));
+
+    let reaches = |threshold: u64| ltv_percent.as_ref().map_or(false, |ltv| *ltv >= percent(threshold));
+    contract::LoanHealth {
+        group_pool_address: loan.group_pool_address.clone(),
+        bid_id: loan.bid_id.clone(),
+        block_number,
+        block_time,
+        status: loan.status,
+        outstanding_principal: outstanding_principal.to_string(),
+        collateral_amount: loan.collateral_amount.clone(),
+        collateral_value: collateral_value.map(|collateral_value| collateral_value.to_string()),
+        price_block_number: price.map(|price| price.evt_block_number),
+        collateral_ratio,
+        above_max_ltv: match (&ltv_percent, &max_ltv_percent) {
+            (Some(ltv), Some(max_ltv)) => ltv > max_ltv,
+            _ => false,
+        },
+        max_ltv_percent: max_ltv_percent.map(|max_ltv| max_ltv.to_string()),
+        warning_level: warning_thresholds.iter().filter(|threshold| reaches(**threshold)).count() as u32,
+        ltv_percent: ltv_percent.map(|ltv| ltv.to_string()),
+        ordinal,
+    }
+}
+
+// loans changed in this block (whatever their status), and the active loans of the pools whose collateral price moved
+pub fn map_loan_health(
+    warning_thresholds: &[u64],
+    block_number: u64,
+    block_time: u64,
+    loans: &contract::Loans,
+    collateral_prices: &contract::CollateralPrices,
+    active_loans: &StoreGetProto<contract::PoolActiveLoans>,
+    latest_loans: &StoreGetProto<contract::Loan>,
+    latest_collateral_prices: &StoreGetProto<contract::CollateralPrice>,
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+) -> contract::LoanHealths {
+    // ordinal of the loan event, or of the Swap for loans only repriced
+    let mut ordinals: BTreeMap<(String, String), u64> = BTreeMap::new();
+    collateral_prices.prices.iter().for_each(|price| {
+        let bid_ids = active_loans
+            .get_last(active_loans_key(&price.group_pool_address))
+            .map(|active| active.bid_ids)
+            .unwrap_or_default();
+        ordinals.extend(bid_ids.into_iter().map(|bid_id| ((price.group_pool_address.clone(), bid_id), price.evt_ordinal)));
+    });
+    // touched loans come from map_loans itself, closed ones get the update of the block that closed them
+    let touched: BTreeMap<(String, String), &contract::Loan> = loans
+        .loans
+        .iter()
+        .map(|loan| ((loan.group_pool_address.clone(), loan.bid_id.clone()), loan))
+        .collect();
+    ordinals.extend(touched.iter().map(|(loan_id, loan)| (loan_id.clone(), loan.updated_ordinal)));
+
+    let mut healths = vec![];
+    for ((pool_address, bid_id), ordinal) in ordinals {
+        let loan = match touched.get(&(pool_address.clone(), bid_id.clone())) {
+            Some(loan) => (*loan).clone(),
+            None => {
+                let Some(loan) = latest_loans.get_last(loans::loan_key(&pool_address, &bid_id)) else {
+                    continue;
+                };
+                loan
+            }
+        };
+        let pool_config = pool_configs.get_last(&pool_address).unwrap_or_default();
+        let price = latest_collateral_prices.get_last(&pool_address);
+        healths.push(loan_health(&loan, price.as_ref(), &pool_config, warning_thresholds, block_number, block_time, ordinal));
+    }
+
+    contract::LoanHealths { healths }
+}
+
+pub fn set_warning_levels(healths: &contract::LoanHealths, store: &StoreSetInt64) {
+    healths.healths.iter().for_each(|health| {
+        store.set(health.ordinal, loans::loan_key(&health.group_pool_address, &health.bid_id), &(health.warning_level as i64));
+    });
+}
+
+// a new loan starts at level 0, so a loan accepted above a warning threshold is flagged too
+pub fn map_loan_health_alerts(healths: &contract::LoanHealths, warning_levels_deltas: &Deltas<DeltaInt64>) -> contract::LoanHealthAlerts {
+    let by_loan: BTreeMap<String, &contract::LoanHealth> = healths
+        .healths
+        .iter()
+        .map(|health| (loans::loan_key(&health.group_pool_address, &health.bid_id), health))
+        .collect();
+
+    contract::LoanHealthAlerts {
+        alerts: warning_levels_deltas
+            .deltas
+            .iter()
+            .filter(|delta| delta.old_value != delta.new_value)
+            .filter_map(|delta| {
+                let health = by_loan.get(&delta.key)?;
+                substreams::log::info!(
+                    "Loan {} of group pool {} went from warning level {} to {} at block {}",
+                    health.bid_id,
+                    health.group_pool_address,
+                    delta.old_value,
+                    delta.new_value,
+                    health.block_number
+                );
+                Some(contract::LoanHealthAlert {
+                    group_pool_address: key::segment_at(&delta.key, 1).to_string(),
+                    bid_id: key::segment_at(&delta.key, 2).to_string(),
+                    block_number: health.block_number,
+                    block_time: health.block_time,
+                    previous_warning_level: delta.old_value as u32,
+                    warning_level: delta.new_value as u32,
+                    ltv_percent: health.ltv_percent.clone(),
+                })
+            })
+            .collect(),
+    }
+}
+
+pub fn db_loan_health_out(healths: &contract::LoanHealths, alerts: &contract::LoanHealthAlerts, tables: &mut DatabaseChangeTables) {
+    healths.healths.iter().for_each(|health| {
+        let row = tables
+            .create_row(
+                "loan_health",
+                [
+                    ("group_pool_address", health.group_pool_address.to_string()),
+                    ("bid_id", health.bid_id.to_string()),
+                    ("block_number", health.block_number.to_string()),
+                ],
+            )
+            .set("block_time", health.block_time)
+            .set("status", loans::status_label(health.status()).to_string())
+            .set("outstanding_principal", BigDecimal::from_str(&health.outstanding_principal).unwrap())
+            .set("collateral_amount", BigDecimal::from_str(&health.collateral_amount).unwrap())
+            .set("collateral_ratio", health.collateral_ratio)
+            .set("above_max_ltv", health.above_max_ltv)
+            .set("warning_level", health.warning_level);
+        for (column, value) in [
+            ("collateral_value", &health.collateral_value),
+            ("ltv_percent", &health.ltv_percent),
+            ("max_ltv_percent", &health.max_ltv_percent),
+        ] {
+            if let Some(value) = value {
+                row.set(column, BigDecimal::from_str(value).unwrap());
+            }
+        }
+        if let Some(price_block_number) = health.price_block_number {
+            row.set("price_block_number", price_block_number);
+        }
+    });
+    alerts.alerts.iter().for_each(|alert| {
+        let row = tables
+            .create_row(
+                "loan_health_alert",
+                [
+                    ("group_pool_address", alert.group_pool_address.to_string()),
+                    ("bid_id", alert.bid_id.to_string()),
+                    ("block_number", alert.block_number.to_string()),
+                ],
+            )
+            .set("block_time", alert.block_time)
+            .set("previous_warning_level", alert.previous_warning_level)
+            .set("warning_level", alert.warning_level);
+        if let Some(ltv_percent) = &alert.ltv_percent {
+            row.set("ltv_percent", BigDecimal::from_str(ltv_percent).unwrap());
+        }
+    });
+}
+
+pub fn graph_loan_health_out(healths: &contract::LoanHealths, alerts: &contract::LoanHealthAlerts, tables: &mut EntityChangesTables) {
+    healths.healths.iter().for_each(|health| {
+        let row = tables
+            .create_row(
+                "loan_health",
+                format!("{}-{}-{}", health.group_pool_address, health.bid_id, health.block_number),
+            )
+            .set("group_pool_address", &health.group_pool_address)
+            .set("bid_id", BigInt::from_str(&health.bid_id).unwrap())
+            .set("block_number", BigInt::from(health.block_number))
+            .set("block_time", BigInt::from(health.block_time))
+            .set("status", loans::status_label(health.status()).to_string())
+            .set("outstanding_principal", BigInt::from_str(&health.outstanding_principal).unwrap())
+            .set("collateral_amount", BigInt::from_str(&health.collateral_amount).unwrap())
+            .set("collateral_ratio", health.collateral_ratio)
+            .set("above_max_ltv", health.above_max_ltv)
+            .set("warning_level", health.warning_level);
+        for (column, value) in [
+            ("collateral_value", &health.collateral_value),
+            ("ltv_percent", &health.ltv_percent),
+            ("max_ltv_percent", &health.max_ltv_percent),
+        ] {
+            if let Some(value) = value {
+                row.set(column, BigDecimal::from_str(value).unwrap());
+            }
+        }
+        if let Some(price_block_number) = health.price_block_number {
+            row.set("price_block_number", BigInt::from(price_block_number));
+        }
+    });
+    alerts.alerts.iter().for_each(|alert| {
+        let row = tables
+            .create_row(
+                "loan_health_alert",
+                format!("{}-{}-{}", alert.group_pool_address, alert.bid_id, alert.block_number),
+            )
+            .set("group_pool_address", &alert.group_pool_address)
+            .set("bid_id", BigInt::from_str(&alert.bid_id).unwrap())
+            .set("block_number", BigInt::from(alert.block_number))
+            .set("block_time", BigInt::from(alert.block_time))
+            .set("previous_warning_level", alert.previous_warning_level)
+            .set("warning_level", alert.warning_level);
+        if let Some(ltv_percent) = &alert.ltv_percent {
+            row.set("ltv_percent", BigDecimal::from_str(ltv_percent).unwrap());
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loan(principal_amount: &str, principal_repaid: &str, collateral_amount: &str) -> contract::Loan {
+        contract::Loan {
+            group_pool_address: "abcd".to_string(),
+            bid_id: "1".to_string(),
+            status: LoanStatus::Active as i32,
+            principal_amount: principal_amount.to_string(),
+            principal_repaid: principal_repaid.to_string(),
+            collateral_amount: collateral_amount.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn price(price: &str) -> contract::CollateralPrice {
+        contract::CollateralPrice {
+            price: price.to_string(),
+            evt_block_number: 10,
+            ..Default::default()
+        }
+    }
+
+    // loan_to_value_percent is the collateral ratio, 12500 requires 1.25 collateral per principal (max LTV 8000)
+    fn pool_config(collateral_ratio: u64) -> contract::PoolConfig {
+        contract::PoolConfig {
+            loan_to_value_percent: collateral_ratio,
+            liquidity_threshold_percent: 9000,
+            ..Default::default()
+        }
+    }
+
+    fn health(loan: &contract::Loan, price: Option<&contract::CollateralPrice>) -> contract::LoanHealth {
+        loan_health(loan, price, &pool_config(12500), &[8000, 9000, 10000], 20, 1_717_000_000, 7)
+    }
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn ltv_is_outstanding_principal_over_collateral_value() {
+        // 1000 collateral at 2 collateral per principal is worth 500 principal, 750 still owed
+        let health = health(&loan("1000", "250", "1000"), Some(&price("2")));
+        assert_eq!(health.outstanding_principal, "750");
+        assert_eq!(decimal(health.collateral_value.as_ref().unwrap()), decimal("500"));
+        assert_eq!(decimal(health.ltv_percent.as_ref().unwrap()), decimal("15000"));
+        assert_eq!(health.collateral_ratio, 12500);
+        assert_eq!(decimal(health.max_ltv_percent.as_ref().unwrap()), decimal("8000"));
+        assert!(health.above_max_ltv);
+        assert_eq!(health.warning_level, 3);
+        assert_eq!(health.price_block_number, Some(10));
+        assert_eq!(health.ordinal, 7);
+    }
+
+    #[test]
+    fn warning_level_counts_the_thresholds_reached() {
+        // 1000 collateral at 1 per principal, 850 owed is an LTV of 85%
+        let health = health(&loan("1000", "150", "1000"), Some(&price("1")));
+        assert_eq!(decimal(health.ltv_percent.as_ref().unwrap()), decimal("8500"));
+        assert_eq!(health.warning_level, 1);
+    }
+
+    #[test]
+    fn max_ltv_is_the_inverse_of_the_collateral_ratio() {
+        // a 15000 collateral ratio allows an LTV of about 6667
+        let ratio_15000 = pool_config(15000);
+        let at = |principal_owed: &str| {
+            loan_health(&loan(principal_owed, "0", "1000"), Some(&price("1")), &ratio_15000, &[], 20, 1_717_000_000, 7)
+        };
+        let max_ltv = decimal(at("600").max_ltv_percent.as_ref().unwrap());
+        assert!(max_ltv > decimal("6666") && max_ltv < decimal("6667"));
+        // 600 owed on 1000 collateral is an LTV of 6000, within the ratio, 700 is past it
+        assert!(!at("600").above_max_ltv);
+        assert!(at("700").above_max_ltv);
+        // at the ratio itself the loan is exactly collateralized
+        let exact = health(&loan("8000", "0", "10000"), Some(&price("1")));
+        assert_eq!(decimal(exact.ltv_percent.as_ref().unwrap()), decimal("8000"));
+        assert!(!exact.above_max_ltv);
+    }
+
+    #[test]
+    fn repaid_above_principal_owes_nothing() {
+        let health = health(&loan("1000", "1200", "1000"), Some(&price("1")));
+        assert_eq!(health.outstanding_principal, "0");
+        assert_eq!(decimal(health.ltv_percent.as_ref().unwrap()), decimal("0"));
+        assert!(!health.above_max_ltv);
+        assert_eq!(health.warning_level, 0);
+    }
+
+    #[test]
+    fn no_ltv_without_a_usable_price_or_collateral() {
+        for health in [
+            health(&loan("1000", "0", "1000"), None),
+            health(&loan("1000", "0", "1000"), Some(&price("0"))),
+            health(&loan("1000", "0", "0"), Some(&price("1"))),
+        ] {
+            assert_eq!(health.collateral_value, None);
+            assert_eq!(health.ltv_percent, None);
+            assert!(!health.above_max_ltv);
+            assert_eq!(health.warning_level, 0);
+        }
+    }
+
+    #[test]
+    fn unset_collateral_ratio_never_triggers() {
+        let health = loan_health(
+            &loan("1000", "0", "1"),
+            Some(&price("1")),
+            &contract::PoolConfig::default(),
+            &[],
+            20,
+            1_717_000_000,
+            7,
+        );
+        assert_eq!(health.max_ltv_percent, None);
+        assert!(!health.above_max_ltv);
+        assert_eq!(health.warning_level, 0);
+    }
+
+    #[test]
+    fn parses_warning_thresholds() {
+        assert_eq!(parse_warning_thresholds("8000,9000,10000").unwrap(), vec![8000, 9000, 10000]);
+        assert_eq!(parse_warning_thresholds(" 10000, 8000 ,9000").unwrap(), vec![8000, 9000, 10000]);
+        assert_eq!(parse_warning_thresholds("8000,,9000,").unwrap(), vec![8000, 9000]);
+    }
+
+    #[test]
+    fn empty_warning_thresholds_are_none() {
+        assert_eq!(parse_warning_thresholds("").unwrap(), Vec::<u64>::new());
+        assert_eq!(parse_warning_thresholds(" , ").unwrap(), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn rejects_invalid_warning_thresholds() {
+        assert!(parse_warning_thresholds("80%").is_err());
+        assert!(parse_warning_thresholds("8000,-1").is_err());
+    }
+}
@@ -0,0 +1,300 @@
+use crate::amounts;
+use crate::pb::contract::v1 as contract;
+use crate::teller_v2;
+use contract::LoanStatus;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreGet, StoreGetBigInt, StoreGetProto, StoreSet, StoreSetProto};
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+pub const PRINCIPAL_REPAID: &str = "principal_repaid";
+pub const INTEREST_REPAID: &str = "interest_repaid";
+
+// loan:{group pool address}:{bid id}
+pub fn loan_key(pool_address: &str, bid_id: &str) -> String {
+    format!("loan:{}:{}", pool_address, bid_id)
+}
+
+// loan:{group pool address}:{bid id}:{total}
+pub fn loan_total_key(pool_address: &str, bid_id: &str, total: &str) -> String {
+    format!("loan:{}:{}:{}", pool_address, bid_id, total)
+}
+
+pub fn status_label(status: LoanStatus) -> &'static str {
+    match status {
+        LoanStatus::Active => "active",
+        LoanStatus::Repaid => "repaid",
+        LoanStatus::Liquidated => "liquidated",
+        LoanStatus::Unspecified => "unspecified",
+    }
+}
+
+pub fn set_loan_originations(events: &contract::Events, store: &StoreSetProto<contract::Loan>) {
+    events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
+        store.set(
+            evt.evt_ordinal,
+            loan_key(&evt.evt_address, &evt.bid_id),
+            &contract::Loan {
+                group_pool_address: evt.evt_address.clone(),
+                bid_id: evt.bid_id.clone(),
+                status: LoanStatus::Active as i32,
+                borrower: evt.borrower.clone(),
+                principal_amount: evt.principal_amount.clone(),
+                collateral_amount: evt.collateral_amount.clone(),
+                loan_duration: evt.loan_duration,
+                interest_rate: evt.interest_rate,
+                accepted_tx_hash: evt.evt_tx_hash.clone(),
+                accepted_block_number: evt.evt_block_number,
+                accepted_at: evt.evt_block_time,
+                principal_repaid: BigInt::zero().to_string(),
+                interest_repaid: BigInt::zero().to_string(),
+                teller_v2_bid: evt.teller_v2_bid.clone(),
+                principal_amount_normalized: evt.principal_amount_normalized.clone(),
+                collateral_amount_normalized: evt.collateral_amount_normalized.clone(),
+                ..Default::default()
+            },
+        );
+    });
+}
+
+// liquidation is terminal, map_loans keeps the status of later repayment deltas of the bid
+pub fn set_loan_liquidations(events: &contract::Events, store: &StoreSetProto<contract::LendergroupDefaultedLoanLiquidated>) {
+    events.lendergroup_defaulted_loan_liquidateds.iter().for_each(|evt| {
+        store.set(evt.evt_ordinal, loan_key(&evt.evt_address, &evt.bid_id), evt);
+    });
+}
+
+pub fn add_loan_repayments(events: &contract::Events, store: &StoreAddBigInt) {
+    // total_principal_repaid / total_interest_collected on LoanRepaid are pool wide, so accumulate per bid here
+    events.lendergroup_loan_repaids.iter().for_each(|evt| {
+        store.add(
+            evt.evt_ordinal,
+            loan_total_key(&evt.evt_address, &evt.bid_id, PRINCIPAL_REPAID),
+            BigInt::from_str(&evt.principal_amount).unwrap(),
+        );
+        store.add(
+            evt.evt_ordinal,
+            loan_total_key(&evt.evt_address, &evt.bid_id, INTEREST_REPAID),
+            BigInt::from_str(&evt.interest_amount).unwrap(),
+        );
+    });
+}
+
+// current state of a loan touched in this block, starting from its accepted snapshot
+fn touch_loan<'a>(
+    loans: &'a mut BTreeMap<(String, String), contract::Loan>,
+    originations: &StoreGetProto<contract::Loan>,
+    liquidations: &StoreGetProto<contract::LendergroupDefaultedLoanLiquidated>,
+    pool_address: &str,
+    bid_id: &str,
+) -> Option<&'a mut contract::Loan> {
+    let loan = match loans.entry((pool_address.to_string(), bid_id.to_string())) {
+        Entry::Occupied(loan) => loan.into_mut(),
+        Entry::Vacant(vacant) => {
+            let Some(mut loan) = originations.get_last(loan_key(pool_address, bid_id)) else {
+                substreams::log::info!("No accepted loan found for bid {} of group pool {}", bid_id, pool_address);
+                return None;
+            };
+            // liquidated in this block or an earlier one
+            if liquidations.has_last(loan_key(pool_address, bid_id)) {
+                loan.status = LoanStatus::Liquidated as i32;
+            }
+            vacant.insert(loan)
+        }
+    };
+    Some(loan)
+}
+
+fn set_updated(loan: &mut contract::Loan, block_number: u64, block_time: u64, ordinal: u64) {
+    loan.updated_block_number = block_number;
+    loan.updated_at = block_time;
+    // events are walked one type at a time
+    loan.updated_ordinal = loan.updated_ordinal.max(ordinal);
+}
+
+pub fn map_loans(
+    events: &contract::Events,
+    originations: &StoreGetProto<contract::Loan>,
+    liquidations: &StoreGetProto<contract::LendergroupDefaultedLoanLiquidated>,
+    repayments: &StoreGetBigInt,
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+) -> contract::Loans {
+    let mut loans: BTreeMap<(String, String), contract::Loan> = BTreeMap::new();
+
+    events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
+        if let Some(loan) = touch_loan(&mut loans, originations, liquidations, &evt.evt_address, &evt.bid_id) {
+            set_updated(loan, evt.evt_block_number, evt.evt_block_time, evt.evt_ordinal);
+        }
+    });
+    events.lendergroup_loan_repaids.iter().for_each(|evt| {
+        if let Some(loan) = touch_loan(&mut loans, originations, liquidations, &evt.evt_address, &evt.bid_id) {
+            set_updated(loan, evt.evt_block_number, evt.evt_block_time, evt.evt_ordinal);
+            loan.last_repaid_at = evt.evt_block_time;
+        }
+    });
+    events.lendergroup_defaulted_loan_liquidateds.iter().for_each(|evt| {
+        if let Some(loan) = touch_loan(&mut loans, originations, liquidations, &evt.evt_address, &evt.bid_id) {
+            set_updated(loan, evt.evt_block_number, evt.evt_block_time, evt.evt_ordinal);
+            loan.liquidated_at = evt.evt_block_time;
+            loan.liquidator = evt.liquidator.clone();
+            loan.liquidation_amount_due = evt.amount_due.clone();
+            loan.liquidation_token_amount_difference = evt.token_amount_difference.clone();
+            loan.liquidation_amount_due_normalized = evt.amount_due_normalized.clone();
+            loan.liquidation_token_amount_difference_normalized = evt.token_amount_difference_normalized.clone();
+        }
+    });
+
+    for ((pool_address, bid_id), loan) in loans.iter_mut() {
+        let principal_repaid = repayments
+            .get_last(loan_total_key(pool_address, bid_id, PRINCIPAL_REPAID))
+            .unwrap_or_else(BigInt::zero);
+        let interest_repaid = repayments
+            .get_last(loan_total_key(pool_address, bid_id, INTEREST_REPAID))
+            .unwrap_or_else(BigInt::zero);
+
+        if loan.status() != LoanStatus::Liquidated
+            && principal_repaid >= BigInt::from_str(&loan.principal_amount).unwrap()
+        {
+            loan.status = LoanStatus::Repaid as i32;
+        }
+        loan.principal_repaid = principal_repaid.to_string();
+        loan.interest_repaid = interest_repaid.to_string();
+
+        let principal_token_decimals = pool_configs
+            .get_last(pool_address)
+            .and_then(|pool_config| pool_config.principal_token_decimals);
+        loan.principal_repaid_normalized = amounts::normalized_amount(&loan.principal_repaid, principal_token_decimals);
+        loan.interest_repaid_normalized = amounts::normalized_amount(&loan.interest_repaid, principal_token_decimals);
+    }
+
+    contract::Loans {
+        loans: loans.into_values().collect(),
+    }
+}
+
+pub fn db_loans_out(loans: &contract::Loans, tables: &mut DatabaseChangeTables) {
+    loans.loans.iter().for_each(|loan| {
+        let row = tables
+            .upsert_row(
+                "loan",
+                [
+                    ("group_pool_address", loan.group_pool_address.to_string()),
+                    ("bid_id", loan.bid_id.to_string()),
+                ],
+            )
+            .set("status", status_label(loan.status()).to_string())
+            .set("borrower", Hex(&loan.borrower).to_string())
+            .set("principal_amount", BigDecimal::from_str(&loan.principal_amount).unwrap())
+            .set("collateral_amount", BigDecimal::from_str(&loan.collateral_amount).unwrap())
+            .set("loan_duration", loan.loan_duration)
+            .set("interest_rate", loan.interest_rate)
+            .set("accepted_tx_hash", &loan.accepted_tx_hash)
+            .set("accepted_block_number", loan.accepted_block_number)
+            .set("accepted_at", loan.accepted_at)
+            .set("principal_repaid", BigDecimal::from_str(&loan.principal_repaid).unwrap())
+            .set("interest_repaid", BigDecimal::from_str(&loan.interest_repaid).unwrap())
+            .set("updated_block_number", loan.updated_block_number)
+            .set("updated_at", loan.updated_at);
+        // scaled amounts are only known once the pool token decimals could be read
+        for (column, amount) in [
+            ("principal_amount_normalized", &loan.principal_amount_normalized),
+            ("collateral_amount_normalized", &loan.collateral_amount_normalized),
+            ("principal_repaid_normalized", &loan.principal_repaid_normalized),
+            ("interest_repaid_normalized", &loan.interest_repaid_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(column, BigDecimal::from_str(amount).unwrap());
+            }
+        }
+        if let Some(bid) = &loan.teller_v2_bid {
+            teller_v2::db_bid_details(bid, row);
+        }
+        // only overwrite the repayment / liquidation timestamps when they happened in this block
+        if loan.last_repaid_at != 0 {
+            row.set("last_repaid_at", loan.last_repaid_at);
+        }
+        if loan.liquidated_at != 0 {
+            row.set("liquidated_at", loan.liquidated_at)
+                .set("liquidator", Hex(&loan.liquidator).to_string())
+                .set("liquidation_amount_due", BigDecimal::from_str(&loan.liquidation_amount_due).unwrap())
+                .set(
+                    "liquidation_token_amount_difference",
+                    BigDecimal::from_str(&loan.liquidation_token_amount_difference).unwrap(),
+                );
+            for (column, amount) in [
+                ("liquidation_amount_due_normalized", &loan.liquidation_amount_due_normalized),
+                ("liquidation_token_amount_difference_normalized", &loan.liquidation_token_amount_difference_normalized),
+            ] {
+                if let Some(amount) = amount {
+                    row.set(column, BigDecimal::from_str(amount).unwrap());
+                }
+            }
+        }
+    });
+}
+
+pub fn graph_loans_out(loans: &contract::Loans, tables: &mut EntityChangesTables) {
+    loans.loans.iter().for_each(|loan| {
+        let id = format!("{}-{}", loan.group_pool_address, loan.bid_id);
+        // the loan entity is created by the block of its BorrowerAcceptedFunds
+        let row = if loan.accepted_block_number == loan.updated_block_number {
+            tables.create_row("loan", id)
+        } else {
+            tables.update_row("loan", id)
+        };
+        row.set("group_pool_address", &loan.group_pool_address)
+            .set("bid_id", BigInt::from_str(&loan.bid_id).unwrap())
+            .set("status", status_label(loan.status()).to_string())
+            .set("borrower", Hex(&loan.borrower).to_string())
+            .set("principal_amount", BigInt::from_str(&loan.principal_amount).unwrap())
+            .set("collateral_amount", BigInt::from_str(&loan.collateral_amount).unwrap())
+            .set("loan_duration", loan.loan_duration)
+            .set("interest_rate", loan.interest_rate)
+            .set("accepted_tx_hash", &loan.accepted_tx_hash)
+            .set("accepted_block_number", BigInt::from(loan.accepted_block_number))
+            .set("accepted_at", BigInt::from(loan.accepted_at))
+            .set("principal_repaid", BigInt::from_str(&loan.principal_repaid).unwrap())
+            .set("interest_repaid", BigInt::from_str(&loan.interest_repaid).unwrap())
+            .set("updated_block_number", BigInt::from(loan.updated_block_number))
+            .set("updated_at", BigInt::from(loan.updated_at));
+        // scaled amounts are only known once the pool token decimals could be read
+        for (field, amount) in [
+            ("principal_amount_normalized", &loan.principal_amount_normalized),
+            ("collateral_amount_normalized", &loan.collateral_amount_normalized),
+            ("principal_repaid_normalized", &loan.principal_repaid_normalized),
+            ("interest_repaid_normalized", &loan.interest_repaid_normalized),
+        ] {
+            if let Some(amount) = amount {
+                row.set(field, BigDecimal::from_str(amount).unwrap());
+            }
+        }
+        if let Some(bid) = &loan.teller_v2_bid {
+            teller_v2::graph_bid_details(bid, row);
+        }
+        // only overwrite the repayment / liquidation timestamps when they happened in this block
+        if loan.last_repaid_at != 0 {
+            row.set("last_repaid_at", BigInt::from(loan.last_repaid_at));
+        }
+        if loan.liquidated_at != 0 {
+            row.set("liquidated_at", BigInt::from(loan.liquidated_at))
+                .set("liquidator", Hex(&loan.liquidator).to_string())
+                .set("liquidation_amount_due", BigInt::from_str(&loan.liquidation_amount_due).unwrap())
+                .set(
+                    "liquidation_token_amount_difference",
+                    BigInt::from_str(&loan.liquidation_token_amount_difference).unwrap(),
+                );
+            for (column, amount) in [
+                ("liquidation_amount_due_normalized", &loan.liquidation_amount_due_normalized),
+                ("liquidation_token_amount_difference_normalized", &loan.liquidation_token_amount_difference_normalized),
+            ] {
+                if let Some(amount) = amount {
+                    row.set(column, BigDecimal::from_str(amount).unwrap());
+                }
+            }
+        }
+    });
+}
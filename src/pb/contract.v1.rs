@@ -30,6 +30,8 @@ pub struct Events {
     pub lendergroup_pool_initializeds: ::prost::alloc::vec::Vec<LendergroupPoolInitialized>,
     #[prost(message, repeated, tag="14")]
     pub lendergroup_unpauseds: ::prost::alloc::vec::Vec<LendergroupUnpaused>,
+    #[prost(message, repeated, tag="15")]
+    pub share_transfers: ::prost::alloc::vec::Vec<ShareTransfer>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -46,6 +48,13 @@ pub struct FactoryAdminChanged {
     pub previous_admin: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="6")]
     pub new_admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="8")]
+    pub factory_version: i32,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -60,6 +69,13 @@ pub struct FactoryBeaconUpgraded {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub beacon: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="7")]
+    pub factory_version: i32,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -74,6 +90,45 @@ pub struct FactoryDeployedLenderGroupContract {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub group_contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="7")]
+    pub factory_version: i32,
+    /// transaction sender
+    #[prost(bytes="vec", tag="8")]
+    pub deployer: ::prost::alloc::vec::Vec<u8>,
+    /// inputs of the deployLenderCommitmentGroupPool call that emitted the log, unset when no call trace matched
+    #[prost(message, optional, tag="9")]
+    pub deploy_call: ::core::option::Option<FactoryDeployLenderCommitmentGroupPoolCall>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="10")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FactoryDeployLenderCommitmentGroupPoolCall {
+    #[prost(string, tag="1")]
+    pub initial_principal_amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="4")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub max_loan_duration: u64,
+    #[prost(uint64, tag="6")]
+    pub interest_rate_lower_bound: u64,
+    #[prost(uint64, tag="7")]
+    pub interest_rate_upper_bound: u64,
+    #[prost(uint64, tag="8")]
+    pub liquidity_threshold_percent: u64,
+    #[prost(uint64, tag="9")]
+    pub loan_to_value_percent: u64,
+    #[prost(uint64, tag="10")]
+    pub uniswap_pool_fee: u64,
+    #[prost(uint64, tag="11")]
+    pub twap_interval: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -88,6 +143,13 @@ pub struct FactoryUpgraded {
     pub evt_block_number: u64,
     #[prost(bytes="vec", tag="5")]
     pub implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="7")]
+    pub factory_version: i32,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -114,6 +176,150 @@ pub struct LendergroupBorrowerAcceptedFunds {
     pub loan_duration: u64,
     #[prost(uint64, tag="11")]
     pub interest_rate: u64,
+    #[prost(bytes="vec", tag="12")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="13")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="14")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="15")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// scaled by the token decimals of the pool, unset when they could not be read
+    #[prost(string, optional, tag="16")]
+    pub principal_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="17")]
+    pub collateral_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// read from the pool's TellerV2 at this block, unset when the pool has no known TellerV2 address
+    #[prost(message, optional, tag="18")]
+    pub teller_v2_bid: ::core::option::Option<TellerV2BidDetails>,
+    #[prost(message, optional, tag="19")]
+    pub collateral_audit: ::core::option::Option<CollateralAudit>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="20")]
+    pub evt_ordinal: u64,
+}
+/// pool oracle views read with eth_call at the end of the block of a loan acceptance, not at the state the pool
+/// saw inside the transaction. Each field is unset when its eth_call failed
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralAudit {
+    /// the pool's TWAP interval in seconds, from PoolInitialized (pool config)
+    #[prost(uint64, optional, tag="1")]
+    pub twap_interval: ::core::option::Option<u64>,
+    /// getSqrtTwapX96(twap_interval) at the end of the block, not the TWAP the pool saw when it accepted the loan
+    #[prost(string, optional, tag="2")]
+    pub sqrt_twap_x96: ::core::option::Option<::prost::alloc::string::String>,
+    /// getAverageWeightedPriceForCollateralTokensPerPrincipalTokens() at the end of the block, as returned by the pool
+    #[prost(string, optional, tag="3")]
+    pub average_weighted_price: ::core::option::Option<::prost::alloc::string::String>,
+    /// getCollateralRequiredForPrincipalAmount(principal_amount) at the end of the block, so it follows any later
+    /// swap or TWAP window roll in the block (see oracle_swapped_after_loan)
+    #[prost(string, optional, tag="4")]
+    pub required_collateral_amount: ::core::option::Option<::prost::alloc::string::String>,
+    /// collateral_amount - required_collateral_amount, negative when the borrower posted less than required. Built
+    /// from the end of block required_collateral_amount, it can be non-zero for a compliant loan
+    #[prost(string, optional, tag="5")]
+    pub collateral_excess: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration="RpcEnrichmentStatus", tag="6")]
+    pub rpc_enrichment_status: i32,
+    /// a Swap of the pool's Uniswap V3 oracle pool came after the loan in the same block, the end of block
+    /// prices (and collateral_excess) may then differ from the ones the pool accepted the loan with
+    #[prost(bool, tag="7")]
+    pub oracle_swapped_after_loan: bool,
+}
+/// bid terms of a loan, each field is unset when its eth_call failed
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2BidDetails {
+    /// bids(bid id)
+    #[prost(string, optional, tag="1")]
+    pub market_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="2")]
+    pub payment_cycle_amount: ::core::option::Option<::prost::alloc::string::String>,
+    /// seconds between two payments
+    #[prost(uint64, optional, tag="3")]
+    pub payment_cycle: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="4")]
+    pub apr: ::core::option::Option<u64>,
+    #[prost(enumeration="TellerV2PaymentType", tag="5")]
+    pub payment_type: i32,
+    #[prost(uint64, optional, tag="6")]
+    pub accepted_timestamp: ::core::option::Option<u64>,
+    /// accepted_timestamp + loan duration
+    #[prost(uint64, optional, tag="7")]
+    pub loan_due_date: ::core::option::Option<u64>,
+    /// calculateNextDueDate(bid id) at acceptance
+    #[prost(uint64, optional, tag="8")]
+    pub first_payment_due_date: ::core::option::Option<u64>,
+    /// bidDefaultDuration(bid id), seconds past a missed payment before the loan defaults
+    #[prost(uint64, optional, tag="9")]
+    pub default_duration: ::core::option::Option<u64>,
+    /// bidPaymentCycleType(bid id)
+    #[prost(enumeration="TellerV2PaymentCycleType", tag="10")]
+    pub payment_cycle_type: i32,
+    #[prost(enumeration="RpcEnrichmentStatus", tag="11")]
+    pub rpc_enrichment_status: i32,
+}
+/// TellerV2 PaymentType shifted by one, unspecified when bids() failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TellerV2PaymentType {
+    Unspecified = 0,
+    Emi = 1,
+    Bullet = 2,
+}
+impl TellerV2PaymentType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TellerV2PaymentType::Unspecified => "TELLER_V2_PAYMENT_TYPE_UNSPECIFIED",
+            TellerV2PaymentType::Emi => "TELLER_V2_PAYMENT_TYPE_EMI",
+            TellerV2PaymentType::Bullet => "TELLER_V2_PAYMENT_TYPE_BULLET",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TELLER_V2_PAYMENT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TELLER_V2_PAYMENT_TYPE_EMI" => Some(Self::Emi),
+            "TELLER_V2_PAYMENT_TYPE_BULLET" => Some(Self::Bullet),
+            _ => None,
+        }
+    }
+}
+/// TellerV2 PaymentCycleType shifted by one, unspecified when bidPaymentCycleType() failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TellerV2PaymentCycleType {
+    Unspecified = 0,
+    Seconds = 1,
+    Monthly = 2,
+}
+impl TellerV2PaymentCycleType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TellerV2PaymentCycleType::Unspecified => "TELLER_V2_PAYMENT_CYCLE_TYPE_UNSPECIFIED",
+            TellerV2PaymentCycleType::Seconds => "TELLER_V2_PAYMENT_CYCLE_TYPE_SECONDS",
+            TellerV2PaymentCycleType::Monthly => "TELLER_V2_PAYMENT_CYCLE_TYPE_MONTHLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TELLER_V2_PAYMENT_CYCLE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TELLER_V2_PAYMENT_CYCLE_TYPE_SECONDS" => Some(Self::Seconds),
+            "TELLER_V2_PAYMENT_CYCLE_TYPE_MONTHLY" => Some(Self::Monthly),
+            _ => None,
+        }
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -136,6 +342,23 @@ pub struct LendergroupDefaultedLoanLiquidated {
     pub amount_due: ::prost::alloc::string::String,
     #[prost(string, tag="9")]
     pub token_amount_difference: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="10")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="11")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="12")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// scaled by the token decimals of the pool, unset when they could not be read
+    #[prost(string, optional, tag="14")]
+    pub amount_due_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="15")]
+    pub token_amount_difference_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="16")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -158,6 +381,23 @@ pub struct LendergroupEarningsWithdrawn {
     pub principal_tokens_withdrawn: ::prost::alloc::string::String,
     #[prost(bytes="vec", tag="9")]
     pub recipient: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="11")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="12")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// scaled by the token decimals of the pool, unset when they could not be read
+    #[prost(string, optional, tag="14")]
+    pub amount_pool_shares_tokens_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="15")]
+    pub principal_tokens_withdrawn_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="16")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -174,6 +414,18 @@ pub struct LendergroupInitialized {
     pub evt_address: ::prost::alloc::string::String,
     #[prost(uint64, tag="6")]
     pub version: u64,
+    #[prost(bytes="vec", tag="7")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="8")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="9")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -196,6 +448,23 @@ pub struct LendergroupLenderAddedPrincipal {
     pub shares_amount: ::prost::alloc::string::String,
     #[prost(bytes="vec", tag="9")]
     pub shares_recipient: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="11")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="12")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// scaled by the token decimals of the pool, unset when they could not be read
+    #[prost(string, optional, tag="14")]
+    pub amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="15")]
+    pub shares_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="16")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -222,6 +491,27 @@ pub struct LendergroupLoanRepaid {
     pub total_principal_repaid: ::prost::alloc::string::String,
     #[prost(string, tag="11")]
     pub total_interest_collected: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="12")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="13")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="14")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="15")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// scaled by the token decimals of the pool, unset when they could not be read
+    #[prost(string, optional, tag="16")]
+    pub principal_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="17")]
+    pub interest_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="18")]
+    pub total_principal_repaid_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="19")]
+    pub total_interest_collected_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="20")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -240,6 +530,18 @@ pub struct LendergroupOwnershipTransferred {
     pub previous_owner: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes="vec", tag="7")]
     pub new_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="10")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -256,6 +558,18 @@ pub struct LendergroupPaused {
     pub evt_address: ::prost::alloc::string::String,
     #[prost(bytes="vec", tag="6")]
     pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="8")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="9")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -292,12 +606,22 @@ pub struct LendergroupPoolInitialized {
     pub twap_interval: u64,
     #[prost(bytes="vec", tag="16")]
     pub pool_shares_token: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="17")]
-    pub uniswap_v3_pool_address: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="18")]
-    pub teller_v2_address: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="19")]
-    pub smart_commitment_forwarder_address: ::prost::alloc::vec::Vec<u8>,
+    /// read from the pool contract over RPC, unset when the eth_call failed
+    #[prost(bytes="vec", optional, tag="17")]
+    pub uniswap_v3_pool_address: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", optional, tag="18")]
+    pub teller_v2_address: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", optional, tag="19")]
+    pub smart_commitment_forwarder_address: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="20")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="21")]
+    pub factory_version: i32,
+    #[prost(enumeration="RpcEnrichmentStatus", tag="22")]
+    pub rpc_enrichment_status: i32,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="23")]
+    pub evt_ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -314,5 +638,1155 @@ pub struct LendergroupUnpaused {
     pub evt_address: ::prost::alloc::string::String,
     #[prost(bytes="vec", tag="6")]
     pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="8")]
+    pub factory_version: i32,
+    /// principal / collateral token of the pool, from store_pool_configs
+    #[prost(bytes="vec", tag="9")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrackedFactories {
+    #[prost(message, repeated, tag="1")]
+    pub factories: ::prost::alloc::vec::Vec<TrackedFactory>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrackedFactory {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub start_block: u64,
+    #[prost(enumeration="FactoryVersion", tag="3")]
+    pub version: i32,
+}
+/// factory / lender group generations with their own generated ABI modules
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FactoryVersion {
+    Unspecified = 0,
+    V1 = 1,
+}
+impl FactoryVersion {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FactoryVersion::Unspecified => "FACTORY_VERSION_UNSPECIFIED",
+            FactoryVersion::V1 => "FACTORY_VERSION_V1",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FACTORY_VERSION_UNSPECIFIED" => Some(Self::Unspecified),
+            "FACTORY_VERSION_V1" => Some(Self::V1),
+            _ => None,
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolMetricsDataPoints {
+    #[prost(message, repeated, tag="1")]
+    pub data_points: ::prost::alloc::vec::Vec<PoolMetricsDataPoint>,
+}
+/// group pool totals as of block_number, the last block of the period that touched them
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolMetricsDataPoint {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(enumeration="DataPointInterval", tag="2")]
+    pub interval: i32,
+    #[prost(uint64, tag="3")]
+    pub period_start: u64,
+    #[prost(uint64, tag="4")]
+    pub block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub block_time: u64,
+    #[prost(string, tag="6")]
+    pub total_principal_tokens_committed: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub total_principal_tokens_withdrawn: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub total_principal_tokens_lended: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub total_principal_tokens_repaid: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub total_interest_collected: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub token_difference_from_liquidations: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub total_principal_tokens_outstanding: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub pool_total_estimated_value: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub utilization_ratio: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DataPointInterval {
+    Unspecified = 0,
+    Block = 1,
+    Hour = 2,
+    Day = 3,
+}
+impl DataPointInterval {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DataPointInterval::Unspecified => "DATA_POINT_INTERVAL_UNSPECIFIED",
+            DataPointInterval::Block => "DATA_POINT_INTERVAL_BLOCK",
+            DataPointInterval::Hour => "DATA_POINT_INTERVAL_HOUR",
+            DataPointInterval::Day => "DATA_POINT_INTERVAL_DAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DATA_POINT_INTERVAL_UNSPECIFIED" => Some(Self::Unspecified),
+            "DATA_POINT_INTERVAL_BLOCK" => Some(Self::Block),
+            "DATA_POINT_INTERVAL_HOUR" => Some(Self::Hour),
+            "DATA_POINT_INTERVAL_DAY" => Some(Self::Day),
+            _ => None,
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Loans {
+    #[prost(message, repeated, tag="1")]
+    pub loans: ::prost::alloc::vec::Vec<Loan>,
+}
+/// lender group loan keyed by group pool address and bid id, accumulated from
+/// BorrowerAcceptedFunds, LoanRepaid and DefaultedLoanLiquidated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Loan {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(enumeration="LoanStatus", tag="3")]
+    pub status: i32,
+    #[prost(bytes="vec", tag="4")]
+    pub borrower: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="5")]
+    pub principal_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collateral_amount: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub loan_duration: u64,
+    #[prost(uint64, tag="8")]
+    pub interest_rate: u64,
+    #[prost(string, tag="9")]
+    pub accepted_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub accepted_block_number: u64,
+    #[prost(uint64, tag="11")]
+    pub accepted_at: u64,
+    #[prost(string, tag="12")]
+    pub principal_repaid: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub interest_repaid: ::prost::alloc::string::String,
+    /// only set when repaid / liquidated in the block the loan was emitted for
+    #[prost(uint64, tag="14")]
+    pub last_repaid_at: u64,
+    #[prost(uint64, tag="15")]
+    pub liquidated_at: u64,
+    #[prost(bytes="vec", tag="16")]
+    pub liquidator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="17")]
+    pub liquidation_amount_due: ::prost::alloc::string::String,
+    #[prost(string, tag="18")]
+    pub liquidation_token_amount_difference: ::prost::alloc::string::String,
+    #[prost(uint64, tag="19")]
+    pub updated_block_number: u64,
+    #[prost(uint64, tag="20")]
+    pub updated_at: u64,
+    /// from the BorrowerAcceptedFunds event
+    #[prost(message, optional, tag="21")]
+    pub teller_v2_bid: ::core::option::Option<TellerV2BidDetails>,
+    /// log ordinal of the last event of the block that touched the loan
+    #[prost(uint64, tag="22")]
+    pub updated_ordinal: u64,
+    /// scaled by the token decimals of the pool, unset when they could not be read
+    #[prost(string, optional, tag="23")]
+    pub principal_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="24")]
+    pub collateral_amount_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="25")]
+    pub principal_repaid_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="26")]
+    pub interest_repaid_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="27")]
+    pub liquidation_amount_due_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="28")]
+    pub liquidation_token_amount_difference_normalized: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// bid ids of the loans of a group pool that are still active, kept by store_active_loans
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolActiveLoans {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="2")]
+    pub bid_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LoanStatus {
+    Unspecified = 0,
+    Active = 1,
+    Repaid = 2,
+    Liquidated = 3,
+}
+impl LoanStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LoanStatus::Unspecified => "LOAN_STATUS_UNSPECIFIED",
+            LoanStatus::Active => "LOAN_STATUS_ACTIVE",
+            LoanStatus::Repaid => "LOAN_STATUS_REPAID",
+            LoanStatus::Liquidated => "LOAN_STATUS_LIQUIDATED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LOAN_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "LOAN_STATUS_ACTIVE" => Some(Self::Active),
+            "LOAN_STATUS_REPAID" => Some(Self::Repaid),
+            "LOAN_STATUS_LIQUIDATED" => Some(Self::Liquidated),
+            _ => None,
+        }
+    }
+}
+/// loan to value of a loan, emitted by map_loan_health when the loan changes (acceptance, repayment, liquidation)
+/// or when the collateral price of its pool moves while it is active
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoanHealths {
+    #[prost(message, repeated, tag="1")]
+    pub healths: ::prost::alloc::vec::Vec<LoanHealth>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoanHealth {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub block_time: u64,
+    #[prost(enumeration="LoanStatus", tag="5")]
+    pub status: i32,
+    /// principal_amount - principal_repaid
+    #[prost(string, tag="6")]
+    pub outstanding_principal: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub collateral_amount: ::prost::alloc::string::String,
+    /// collateral_amount at the pool's collateral price, in principal token base units. Unset (as is ltv_percent)
+    /// until the pool's Uniswap V3 pool has swapped, or when the loan has no collateral
+    #[prost(string, optional, tag="8")]
+    pub collateral_value: ::core::option::Option<::prost::alloc::string::String>,
+    /// outstanding_principal / collateral_value, in the pool's percent units (10000 = 100%)
+    #[prost(string, optional, tag="9")]
+    pub ltv_percent: ::core::option::Option<::prost::alloc::string::String>,
+    /// block of the collateral price used
+    #[prost(uint64, optional, tag="10")]
+    pub price_block_number: ::core::option::Option<u64>,
+    /// the pool's collateralRatio (loan_to_value_percent of PoolInitialized), collateral required per unit of principal
+    #[prost(uint64, tag="11")]
+    pub collateral_ratio: u64,
+    /// LTV at which the loan holds exactly the required collateral, 10000 * 10000 / collateral_ratio. Unset when
+    /// the pool has no collateral ratio
+    #[prost(string, optional, tag="12")]
+    pub max_ltv_percent: ::core::option::Option<::prost::alloc::string::String>,
+    /// ltv_percent is above max_ltv_percent, the collateral no longer covers the pool's collateral ratio
+    #[prost(bool, tag="13")]
+    pub above_max_ltv: bool,
+    /// number of the map_loan_health warning thresholds (params) that ltv_percent reaches
+    #[prost(uint32, tag="15")]
+    pub warning_level: u32,
+    /// log ordinal of the loan event, or of the Swap when only the collateral price moved
+    #[prost(uint64, tag="16")]
+    pub ordinal: u64,
+}
+/// loans whose warning_level changed, emitted by map_loan_health_alerts
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoanHealthAlerts {
+    #[prost(message, repeated, tag="1")]
+    pub alerts: ::prost::alloc::vec::Vec<LoanHealthAlert>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoanHealthAlert {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub block_time: u64,
+    #[prost(uint32, tag="5")]
+    pub previous_warning_level: u32,
+    #[prost(uint32, tag="6")]
+    pub warning_level: u32,
+    #[prost(string, optional, tag="7")]
+    pub ltv_percent: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Transfer of the pool shares token of a group pool (mints and burns included)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// the shares token
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="9")]
+    pub value: ::prost::alloc::string::String,
+    /// scaled by the shares token decimals, unset when they could not be read
+    #[prost(string, optional, tag="10")]
+    pub value_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+}
+/// lender group pool calls decoded from the block call traces by map_calls, reverted calls included
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Calls {
+    #[prost(message, repeated, tag="1")]
+    pub lendergroup_call_add_principal_to_commitment_groups: ::prost::alloc::vec::Vec<LendergroupAddPrincipalToCommitmentGroupCall>,
+    #[prost(message, repeated, tag="2")]
+    pub lendergroup_call_burn_shares_to_withdraw_earnings: ::prost::alloc::vec::Vec<LendergroupBurnSharesToWithdrawEarningsCall>,
+    #[prost(message, repeated, tag="3")]
+    pub lendergroup_call_liquidate_defaulted_loan_with_incentives: ::prost::alloc::vec::Vec<LendergroupLiquidateDefaultedLoanWithIncentiveCall>,
+    #[prost(message, repeated, tag="4")]
+    pub lendergroup_call_pause_borrowings: ::prost::alloc::vec::Vec<LendergroupPauseBorrowingCall>,
+    #[prost(message, repeated, tag="5")]
+    pub lendergroup_call_unpause_borrowings: ::prost::alloc::vec::Vec<LendergroupUnpauseBorrowingCall>,
+    #[prost(message, repeated, tag="6")]
+    pub lendergroup_call_accept_funds_for_accept_bids: ::prost::alloc::vec::Vec<LendergroupAcceptFundsForAcceptBidCall>,
+    #[prost(message, repeated, tag="7")]
+    pub lendergroup_call_repay_loan_callbacks: ::prost::alloc::vec::Vec<LendergroupRepayLoanCallbackCall>,
+    /// calls that failed themselves, decoded or not, picked up by map_reverted_calls
+    #[prost(message, repeated, tag="8")]
+    pub reverted_calls: ::prost::alloc::vec::Vec<RevertedCall>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupAddPrincipalToCommitmentGroupCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    #[prost(string, tag="10")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="11")]
+    pub shares_recipient: ::prost::alloc::vec::Vec<u8>,
+    /// return value, unset when the call reverted
+    #[prost(string, optional, tag="12")]
+    pub output_shares_amount: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupBurnSharesToWithdrawEarningsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    #[prost(string, tag="10")]
+    pub amount_pool_shares_tokens: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="11")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    /// return value, unset when the call reverted
+    #[prost(string, optional, tag="12")]
+    pub output_principal_tokens_withdrawn: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupLiquidateDefaultedLoanWithIncentiveCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    #[prost(string, tag="10")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub token_amount_difference: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupPauseBorrowingCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupUnpauseBorrowingCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupAcceptFundsForAcceptBidCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    #[prost(bytes="vec", tag="10")]
+    pub borrower: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="11")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub principal_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub collateral_amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="14")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="15")]
+    pub collateral_token_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="16")]
+    pub loan_duration: u64,
+    #[prost(uint64, tag="17")]
+    pub interest_rate: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendergroupRepayLoanCallbackCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    #[prost(string, tag="10")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="11")]
+    pub repayer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="12")]
+    pub principal_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub interest_amount: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevertedCalls {
+    #[prost(message, repeated, tag="1")]
+    pub calls: ::prost::alloc::vec::Vec<RevertedCall>,
+}
+/// call trace to a tracked pool that failed itself (not only reverted by its parent), emitted by map_reverted_calls
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevertedCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub call_block_time: u64,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(string, tag="5")]
+    pub call_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    /// transaction sender, the caller is often the smart commitment forwarder or TellerV2
+    #[prost(bytes="vec", tag="7")]
+    pub tx_from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="9")]
+    pub factory_version: i32,
+    /// solidity function name, the 0x prefixed selector when no decoder matched
+    #[prost(string, tag="10")]
+    pub function: ::prost::alloc::string::String,
+    /// decoded input arguments, empty when the input could not be decoded
+    #[prost(message, repeated, tag="11")]
+    pub arguments: ::prost::alloc::vec::Vec<CallArgument>,
+    #[prost(uint64, tag="12")]
+    pub gas_used: u64,
+    /// Error(string) message, Panic(uint256) code or custom error selector, unset when there is no revert data
+    #[prost(string, optional, tag="13")]
+    pub revert_reason: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bytes="vec", tag="14")]
+    pub revert_data: ::prost::alloc::vec::Vec<u8>,
+    /// failure reason reported by the node (e.g. "execution reverted", "out of gas")
+    #[prost(string, tag="15")]
+    pub failure_reason: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallArgument {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// decimal for integers, hex for addresses
+    #[prost(string, tag="2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// TellerV2 events of the bids accepted by a tracked pool (BorrowerAcceptedFunds), emitted by map_teller_v2_events.
+/// A bid submitted in an earlier block comes out of store_teller_v2_submitted_bids in the block of its acceptance
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2Events {
+    #[prost(message, repeated, tag="1")]
+    pub teller_v2_submitted_bids: ::prost::alloc::vec::Vec<TellerV2SubmittedBid>,
+    #[prost(message, repeated, tag="2")]
+    pub teller_v2_accepted_bids: ::prost::alloc::vec::Vec<TellerV2AcceptedBid>,
+    #[prost(message, repeated, tag="3")]
+    pub teller_v2_cancelled_bids: ::prost::alloc::vec::Vec<TellerV2CancelledBid>,
+    #[prost(message, repeated, tag="4")]
+    pub teller_v2_market_owner_cancelled_bids: ::prost::alloc::vec::Vec<TellerV2MarketOwnerCancelledBid>,
+    #[prost(message, repeated, tag="5")]
+    pub teller_v2_loan_repayments: ::prost::alloc::vec::Vec<TellerV2LoanRepayment>,
+    #[prost(message, repeated, tag="6")]
+    pub teller_v2_loan_repaids: ::prost::alloc::vec::Vec<TellerV2LoanRepaid>,
+    #[prost(message, repeated, tag="7")]
+    pub teller_v2_loan_liquidateds: ::prost::alloc::vec::Vec<TellerV2LoanLiquidated>,
+    #[prost(message, repeated, tag="8")]
+    pub teller_v2_loan_closeds: ::prost::alloc::vec::Vec<TellerV2LoanClosed>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2SubmittedBid {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub borrower: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub receiver: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub metadata_uri: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2AcceptedBid {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub lender: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2CancelledBid {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2MarketOwnerCancelledBid {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2LoanRepayment {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2LoanRepaid {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2LoanLiquidated {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub liquidator: ::prost::alloc::vec::Vec<u8>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TellerV2LoanClosed {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    /// TellerV2 contract
+    #[prost(string, tag="5")]
+    pub evt_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub bid_id: ::prost::alloc::string::String,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+}
+/// collateral price of the group pools, read from the Swap logs of their Uniswap V3 oracle pool (uniswap_v3_pool_address
+/// of the pool config). One price per group pool and block, from the last Swap of the block
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralPrices {
+    #[prost(message, repeated, tag="1")]
+    pub prices: ::prost::alloc::vec::Vec<CollateralPrice>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralPrice {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub uniswap_v3_pool_address: ::prost::alloc::string::String,
+    /// last Swap of the block
+    #[prost(string, tag="3")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="5")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="6")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="7")]
+    pub sqrt_price_x96: ::prost::alloc::string::String,
+    #[prost(int32, tag="8")]
+    pub tick: i32,
+    /// collateral token base units per principal token base unit
+    #[prost(string, tag="9")]
+    pub price: ::prost::alloc::string::String,
+    /// collateral tokens per principal token, unset when the decimals of either token are unknown
+    #[prost(string, optional, tag="10")]
+    pub price_normalized: ::core::option::Option<::prost::alloc::string::String>,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareExchangeRates {
+    #[prost(message, repeated, tag="1")]
+    pub rates: ::prost::alloc::vec::Vec<ShareExchangeRate>,
+}
+/// principal per share implied by a deposit or a withdrawal, in base units of both tokens
+/// (SharesExchangeRate of the pool without its expansion factor)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareExchangeRate {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="4")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="5")]
+    pub evt_block_number: u64,
+    #[prost(enumeration="ShareExchangeRateSource", tag="6")]
+    pub source: i32,
+    #[prost(string, tag="7")]
+    pub principal_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub shares_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub exchange_rate: ::prost::alloc::string::String,
+    /// log ordinal in the block, stores are written at it
+    #[prost(uint64, tag="10")]
+    pub evt_ordinal: u64,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ShareExchangeRateSource {
+    Unspecified = 0,
+    LenderAddedPrincipal = 1,
+    EarningsWithdrawn = 2,
+}
+impl ShareExchangeRateSource {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ShareExchangeRateSource::Unspecified => "SHARE_EXCHANGE_RATE_SOURCE_UNSPECIFIED",
+            ShareExchangeRateSource::LenderAddedPrincipal => "SHARE_EXCHANGE_RATE_SOURCE_LENDER_ADDED_PRINCIPAL",
+            ShareExchangeRateSource::EarningsWithdrawn => "SHARE_EXCHANGE_RATE_SOURCE_EARNINGS_WITHDRAWN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SHARE_EXCHANGE_RATE_SOURCE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SHARE_EXCHANGE_RATE_SOURCE_LENDER_ADDED_PRINCIPAL" => Some(Self::LenderAddedPrincipal),
+            "SHARE_EXCHANGE_RATE_SOURCE_EARNINGS_WITHDRAWN" => Some(Self::EarningsWithdrawn),
+            _ => None,
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareExchangeRateCandles {
+    #[prost(message, repeated, tag="1")]
+    pub candles: ::prost::alloc::vec::Vec<ShareExchangeRateCandle>,
+}
+/// daily OHLC of the implied exchange rates, as of block_number, the last block of the day with a rate
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareExchangeRateCandle {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub period_start: u64,
+    #[prost(string, tag="3")]
+    pub open: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub high: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub low: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub close: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    #[prost(uint64, tag="8")]
+    pub block_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolOnchainSnapshots {
+    #[prost(message, repeated, tag="1")]
+    pub snapshots: ::prost::alloc::vec::Vec<PoolOnchainSnapshot>,
+}
+/// pool view functions read over RPC at block_number, every field is unset when its eth_call failed
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolOnchainSnapshot {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(uint64, tag="3")]
+    pub block_time: u64,
+    #[prost(uint64, optional, tag="4")]
+    pub pool_utilization_ratio: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="5")]
+    pub shares_exchange_rate: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub principal_amount_available_to_borrow: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub total_principal_tokens_committed: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub total_principal_tokens_outstanding_in_active_loans: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="9")]
+    pub total_principal_tokens_lended: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="10")]
+    pub total_principal_tokens_repaid: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="11")]
+    pub total_interest_collected: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="12")]
+    pub total_collateral_tokens_escrowed_for_loans: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag="13")]
+    pub paused: ::core::option::Option<bool>,
+    #[prost(enumeration="RpcEnrichmentStatus", tag="14")]
+    pub rpc_enrichment_status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReconciliationMismatches {
+    #[prost(message, repeated, tag="1")]
+    pub mismatches: ::prost::alloc::vec::Vec<ReconciliationMismatch>,
+}
+/// event-derived pool total (store_pool_totals) that diverges from the contract counter of the same block
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReconciliationMismatch {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(uint64, tag="3")]
+    pub block_time: u64,
+    /// name of the total, as in store_pool_totals
+    #[prost(string, tag="4")]
+    pub total: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub event_derived_value: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub onchain_value: ::prost::alloc::string::String,
+    /// onchain_value - event_derived_value
+    #[prost(string, tag="7")]
+    pub difference: ::prost::alloc::string::String,
+}
+/// group pool configuration from PoolInitialized, keyed by group pool address in store_pool_configs
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolConfig {
+    #[prost(string, tag="1")]
+    pub group_pool_address: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub factory_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="FactoryVersion", tag="3")]
+    pub factory_version: i32,
+    #[prost(bytes="vec", tag="4")]
+    pub principal_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub collateral_token_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub pool_shares_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub max_loan_duration: u64,
+    #[prost(uint64, tag="9")]
+    pub interest_rate_lower_bound: u64,
+    #[prost(uint64, tag="10")]
+    pub interest_rate_upper_bound: u64,
+    #[prost(uint64, tag="11")]
+    pub liquidity_threshold_percent: u64,
+    #[prost(uint64, tag="12")]
+    pub loan_to_value_percent: u64,
+    #[prost(uint64, tag="13")]
+    pub uniswap_pool_fee: u64,
+    #[prost(uint64, tag="14")]
+    pub twap_interval: u64,
+    /// read from the pool contract over RPC, unset when the eth_call failed
+    #[prost(bytes="vec", optional, tag="15")]
+    pub uniswap_v3_pool_address: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", optional, tag="16")]
+    pub teller_v2_address: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", optional, tag="17")]
+    pub smart_commitment_forwarder_address: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(enumeration="RpcEnrichmentStatus", tag="18")]
+    pub rpc_enrichment_status: i32,
+    /// decimals() of the pool tokens, read in the same RpcBatch, unset when the eth_call failed
+    #[prost(uint64, optional, tag="19")]
+    pub principal_token_decimals: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="20")]
+    pub collateral_token_decimals: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="21")]
+    pub pool_shares_token_decimals: ::core::option::Option<u64>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RpcEnrichmentStatus {
+    Unspecified = 0,
+    Complete = 1,
+    Partial = 2,
+    Failed = 3,
+}
+impl RpcEnrichmentStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RpcEnrichmentStatus::Unspecified => "RPC_ENRICHMENT_STATUS_UNSPECIFIED",
+            RpcEnrichmentStatus::Complete => "RPC_ENRICHMENT_STATUS_COMPLETE",
+            RpcEnrichmentStatus::Partial => "RPC_ENRICHMENT_STATUS_PARTIAL",
+            RpcEnrichmentStatus::Failed => "RPC_ENRICHMENT_STATUS_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RPC_ENRICHMENT_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "RPC_ENRICHMENT_STATUS_COMPLETE" => Some(Self::Complete),
+            "RPC_ENRICHMENT_STATUS_PARTIAL" => Some(Self::Partial),
+            "RPC_ENRICHMENT_STATUS_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RpcFailures {
+    #[prost(message, repeated, tag="1")]
+    pub failures: ::prost::alloc::vec::Vec<RpcFailure>,
+}
+/// eth_call that returned nothing while enriching an event, the event itself is still emitted
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RpcFailure {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(uint64, tag="3")]
+    pub evt_block_time: u64,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub contract_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub method: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Tokens {
+    #[prost(message, repeated, tag="1")]
+    pub tokens: ::prost::alloc::vec::Vec<Token>,
+}
+/// ERC20 metadata read over RPC the first time the token appears in a PoolInitialized
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Token {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    /// unset when the eth_call failed (name / symbol also fall back to a bytes32 return value)
+    #[prost(string, optional, tag="2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub symbol: ::core::option::Option<::prost::alloc::string::String>,
+    /// from the pool config of the pool the token appeared in, where decimals() is read
+    #[prost(uint64, optional, tag="4")]
+    pub decimals: ::core::option::Option<u64>,
+    #[prost(enumeration="RpcEnrichmentStatus", tag="5")]
+    pub rpc_enrichment_status: i32,
+    #[prost(uint64, tag="6")]
+    pub block_number: u64,
 }
 // @@protoc_insertion_point(module)
@@ -0,0 +1,33 @@
+use crate::abi;
+use crate::pb::contract::v1 as contract;
+use substreams::Hex;
+
+// store_pool_configs key, the group pool address
+pub fn pool_config_key(pool_address: &[u8]) -> String {
+    Hex(pool_address).to_string()
+}
+
+// RPC fields are filled by rpc::enrich_pool_configs
+pub fn v1_pool_config(
+    pool_address: &[u8],
+    factory: &contract::TrackedFactory,
+    event: abi::lendergroup_contract::events::PoolInitialized,
+) -> contract::PoolConfig {
+    contract::PoolConfig {
+        group_pool_address: Hex(pool_address).to_string(),
+        factory_address: factory.address.clone(),
+        factory_version: factory.version,
+        principal_token_address: event.principal_token_address,
+        collateral_token_address: event.collateral_token_address,
+        pool_shares_token: event.pool_shares_token,
+        market_id: event.market_id.to_string(),
+        max_loan_duration: event.max_loan_duration.to_u64(),
+        interest_rate_lower_bound: event.interest_rate_lower_bound.to_u64(),
+        interest_rate_upper_bound: event.interest_rate_upper_bound.to_u64(),
+        liquidity_threshold_percent: event.liquidity_threshold_percent.to_u64(),
+        loan_to_value_percent: event.loan_to_value_percent.to_u64(),
+        uniswap_pool_fee: event.uniswap_pool_fee.to_u64(),
+        twap_interval: event.twap_interval.to_u64(),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,161 @@
+use crate::pb::contract::v1 as contract;
+use crate::rpc_failures;
+use std::str::FromStr;
+use substreams::key;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt};
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+pub const TOTAL_PRINCIPAL_TOKENS_COMMITTED: &str = "total_principal_tokens_committed";
+pub const TOTAL_PRINCIPAL_TOKENS_WITHDRAWN: &str = "total_principal_tokens_withdrawn";
+pub const TOTAL_PRINCIPAL_TOKENS_LENDED: &str = "total_principal_tokens_lended";
+pub const TOTAL_PRINCIPAL_TOKENS_REPAID: &str = "total_principal_tokens_repaid";
+pub const TOTAL_INTEREST_COLLECTED: &str = "total_interest_collected";
+pub const TOKEN_DIFFERENCE_FROM_LIQUIDATIONS: &str = "token_difference_from_liquidations";
+
+pub const POOL_TOTALS: [&str; 6] = [
+    TOTAL_PRINCIPAL_TOKENS_COMMITTED,
+    TOTAL_PRINCIPAL_TOKENS_WITHDRAWN,
+    TOTAL_PRINCIPAL_TOKENS_LENDED,
+    TOTAL_PRINCIPAL_TOKENS_REPAID,
+    TOTAL_INTEREST_COLLECTED,
+    TOKEN_DIFFERENCE_FROM_LIQUIDATIONS,
+];
+
+// pool:{group pool address}:{total}
+pub fn pool_total_key(pool_address: &str, total: &str) -> String {
+    format!("pool:{}:{}", pool_address, total)
+}
+
+pub fn add_pool_totals(events: &contract::Events, store: &StoreAddBigInt) {
+    events.lendergroup_lender_added_principals.iter().for_each(|evt| {
+        store.add(
+            evt.evt_ordinal,
+            pool_total_key(&evt.evt_address, TOTAL_PRINCIPAL_TOKENS_COMMITTED),
+            BigInt::from_str(&evt.amount).unwrap(),
+        );
+    });
+    events.lendergroup_earnings_withdrawns.iter().for_each(|evt| {
+        store.add(
+            evt.evt_ordinal,
+            pool_total_key(&evt.evt_address, TOTAL_PRINCIPAL_TOKENS_WITHDRAWN),
+            BigInt::from_str(&evt.principal_tokens_withdrawn).unwrap(),
+        );
+    });
+    events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
+        store.add(
+            evt.evt_ordinal,
+            pool_total_key(&evt.evt_address, TOTAL_PRINCIPAL_TOKENS_LENDED),
+            BigInt::from_str(&evt.principal_amount).unwrap(),
+        );
+    });
+    events.lendergroup_loan_repaids.iter().for_each(|evt| {
+        store.add(
+            evt.evt_ordinal,
+            pool_total_key(&evt.evt_address, TOTAL_PRINCIPAL_TOKENS_REPAID),
+            BigInt::from_str(&evt.principal_amount).unwrap(),
+        );
+        store.add(
+            evt.evt_ordinal,
+            pool_total_key(&evt.evt_address, TOTAL_INTEREST_COLLECTED),
+            BigInt::from_str(&evt.interest_amount).unwrap(),
+        );
+    });
+    events.lendergroup_defaulted_loan_liquidateds.iter().for_each(|evt| {
+        // signed: negative when the liquidator paid less than the amount due
+        store.add(
+            evt.evt_ordinal,
+            pool_total_key(&evt.evt_address, TOKEN_DIFFERENCE_FROM_LIQUIDATIONS),
+            BigInt::from_str(&evt.token_amount_difference).unwrap(),
+        );
+    });
+}
+
+pub fn db_pool_metrics_out(
+    events: &contract::Events,
+    pool_totals_deltas: &Deltas<DeltaBigInt>,
+    tables: &mut DatabaseChangeTables,
+) {
+    // pool config is only known from PoolInitialized, totals start at zero
+    events.lendergroup_pool_initializeds.iter().for_each(|evt| {
+        let row = tables
+            .upsert_row("group_pool_metrics", evt.evt_address.as_str())
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
+            .set("shares_token_address", Hex(&evt.pool_shares_token).to_string())
+            .set("market_id", BigInt::from_str(&evt.market_id).unwrap())
+            .set("uniswap_pool_fee", evt.uniswap_pool_fee)
+            .set("max_loan_duration", evt.max_loan_duration)
+            .set("twap_interval", evt.twap_interval)
+            .set("interest_rate_upper_bound", evt.interest_rate_upper_bound)
+            .set("interest_rate_lower_bound", evt.interest_rate_lower_bound)
+            .set("liquidity_threshold_percent", evt.liquidity_threshold_percent)
+            .set("collateral_ratio", evt.loan_to_value_percent)
+            .set("rpc_enrichment_status", rpc_failures::status_label(evt.rpc_enrichment_status()).to_string());
+        // RPC fields are left unset when their eth_call failed
+        for (field, value) in [
+            ("uniswap_v3_pool_address", &evt.uniswap_v3_pool_address),
+            ("teller_v2_address", &evt.teller_v2_address),
+            ("smart_commitment_forwarder_address", &evt.smart_commitment_forwarder_address),
+        ] {
+            if let Some(address) = value {
+                row.set(field, Hex(address).to_string());
+            }
+        }
+        for total in POOL_TOTALS {
+            row.set(total, BigInt::zero());
+        }
+    });
+
+    pool_totals_deltas.deltas.iter().for_each(|delta| {
+        tables
+            .upsert_row("group_pool_metrics", key::segment_at(&delta.key, 1))
+            .set(key::last_segment(&delta.key), delta.new_value.clone());
+    });
+}
+
+pub fn graph_pool_metrics_out(
+    events: &contract::Events,
+    pool_totals_deltas: &Deltas<DeltaBigInt>,
+    tables: &mut EntityChangesTables,
+) {
+    // pool config is only known from PoolInitialized, totals start at zero
+    events.lendergroup_pool_initializeds.iter().for_each(|evt| {
+        let row = tables
+            .create_row("group_pool_metrics", &evt.evt_address)
+            .set("group_pool_address", &evt.evt_address)
+            .set("principal_token_address", Hex(&evt.principal_token_address).to_string())
+            .set("collateral_token_address", Hex(&evt.collateral_token_address).to_string())
+            .set("shares_token_address", Hex(&evt.pool_shares_token).to_string())
+            .set("market_id", BigInt::from_str(&evt.market_id).unwrap())
+            .set("uniswap_pool_fee", evt.uniswap_pool_fee)
+            .set("max_loan_duration", evt.max_loan_duration)
+            .set("twap_interval", evt.twap_interval)
+            .set("interest_rate_upper_bound", evt.interest_rate_upper_bound)
+            .set("interest_rate_lower_bound", evt.interest_rate_lower_bound)
+            .set("liquidity_threshold_percent", evt.liquidity_threshold_percent)
+            .set("collateral_ratio", evt.loan_to_value_percent)
+            .set("rpc_enrichment_status", rpc_failures::status_label(evt.rpc_enrichment_status()).to_string());
+        // RPC fields are left unset when their eth_call failed
+        for (field, value) in [
+            ("uniswap_v3_pool_address", &evt.uniswap_v3_pool_address),
+            ("teller_v2_address", &evt.teller_v2_address),
+            ("smart_commitment_forwarder_address", &evt.smart_commitment_forwarder_address),
+        ] {
+            if let Some(address) = value {
+                row.set(field, Hex(address).to_string());
+            }
+        }
+        for total in POOL_TOTALS {
+            row.set(total, BigInt::zero());
+        }
+    });
+
+    pool_totals_deltas.deltas.iter().for_each(|delta| {
+        tables
+            .update_row("group_pool_metrics", key::segment_at(&delta.key, 1))
+            .set(key::last_segment(&delta.key), delta.new_value.clone());
+    });
+}
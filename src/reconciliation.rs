@@ -0,0 +1,93 @@
+use crate::pb::contract::v1 as contract;
+use crate::pool_metrics;
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{StoreGet, StoreGetBigInt};
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+// event-derived totals that have an on-chain counter, and the snapshot field holding it
+fn reconciled_totals(snapshot: &contract::PoolOnchainSnapshot) -> [(&'static str, &Option<String>); 4] {
+    [
+        (pool_metrics::TOTAL_PRINCIPAL_TOKENS_COMMITTED, &snapshot.total_principal_tokens_committed),
+        (pool_metrics::TOTAL_PRINCIPAL_TOKENS_LENDED, &snapshot.total_principal_tokens_lended),
+        (pool_metrics::TOTAL_PRINCIPAL_TOKENS_REPAID, &snapshot.total_principal_tokens_repaid),
+        (pool_metrics::TOTAL_INTEREST_COLLECTED, &snapshot.total_interest_collected),
+    ]
+}
+
+// both sides are as of the end of the snapshot block, a failed eth_call is not a mismatch
+pub fn map_reconciliation_mismatches(
+    snapshots: &contract::PoolOnchainSnapshots,
+    pool_totals: &StoreGetBigInt,
+) -> contract::ReconciliationMismatches {
+    let mut mismatches = contract::ReconciliationMismatches::default();
+
+    for snapshot in snapshots.snapshots.iter() {
+        for (total, onchain_value) in reconciled_totals(snapshot) {
+            let Some(onchain_value) = onchain_value else { continue };
+            let onchain_value = BigInt::from_str(onchain_value).unwrap();
+            let event_derived_value = pool_totals
+                .get_last(pool_metrics::pool_total_key(&snapshot.group_pool_address, total))
+                .unwrap_or_else(BigInt::zero);
+            if onchain_value == event_derived_value {
+                continue;
+            }
+
+            substreams::log::info!(
+                "{} of group pool {} is {} on-chain but {} from events at block {}",
+                total,
+                snapshot.group_pool_address,
+                onchain_value,
+                event_derived_value,
+                snapshot.block_number
+            );
+            mismatches.mismatches.push(contract::ReconciliationMismatch {
+                group_pool_address: snapshot.group_pool_address.clone(),
+                block_number: snapshot.block_number,
+                block_time: snapshot.block_time,
+                total: total.to_string(),
+                difference: (onchain_value.clone() - event_derived_value.clone()).to_string(),
+                event_derived_value: event_derived_value.to_string(),
+                onchain_value: onchain_value.to_string(),
+            });
+        }
+    }
+
+    mismatches
+}
+
+pub fn db_reconciliation_mismatches_out(mismatches: &contract::ReconciliationMismatches, tables: &mut DatabaseChangeTables) {
+    mismatches.mismatches.iter().for_each(|mismatch| {
+        tables
+            .create_row(
+                "reconciliation_mismatch",
+                [
+                    ("group_pool_address", mismatch.group_pool_address.to_string()),
+                    ("block_number", mismatch.block_number.to_string()),
+                    ("total", mismatch.total.to_string()),
+                ],
+            )
+            .set("block_time", mismatch.block_time)
+            .set("event_derived_value", BigDecimal::from_str(&mismatch.event_derived_value).unwrap())
+            .set("onchain_value", BigDecimal::from_str(&mismatch.onchain_value).unwrap())
+            .set("difference", BigDecimal::from_str(&mismatch.difference).unwrap());
+    });
+}
+
+pub fn graph_reconciliation_mismatches_out(mismatches: &contract::ReconciliationMismatches, tables: &mut EntityChangesTables) {
+    mismatches.mismatches.iter().for_each(|mismatch| {
+        tables
+            .create_row(
+                "reconciliation_mismatch",
+                format!("{}-{}-{}", mismatch.group_pool_address, mismatch.block_number, mismatch.total),
+            )
+            .set("group_pool_address", &mismatch.group_pool_address)
+            .set("block_number", BigInt::from(mismatch.block_number))
+            .set("block_time", BigInt::from(mismatch.block_time))
+            .set("total", &mismatch.total)
+            .set("event_derived_value", BigInt::from_str(&mismatch.event_derived_value).unwrap())
+            .set("onchain_value", BigInt::from_str(&mismatch.onchain_value).unwrap())
+            .set("difference", BigInt::from_str(&mismatch.difference).unwrap());
+    });
+}
@@ -0,0 +1,148 @@
+use crate::calls::LendergroupCall;
+use crate::factories;
+use crate::pb::contract::v1 as contract;
+use crate::rpc;
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+// Error(string) and Panic(uint256) selectors, any other revert data is a custom error
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+fn argument(name: &str, value: String) -> contract::CallArgument {
+    contract::CallArgument {
+        name: name.to_string(),
+        value,
+    }
+}
+
+// input arguments of a decoded call, values are decimal or hex strings
+fn call_arguments(decoded: &LendergroupCall) -> Vec<contract::CallArgument> {
+    match decoded {
+        LendergroupCall::AddPrincipalToCommitmentGroup(decoded) => vec![
+            argument("amount", decoded.u_amount.to_string()),
+            argument("shares_recipient", Hex(&decoded.u_shares_recipient).to_string()),
+        ],
+        LendergroupCall::BurnSharesToWithdrawEarnings(decoded) => vec![
+            argument("amount_pool_shares_tokens", decoded.u_amount_pool_shares_tokens.to_string()),
+            argument("recipient", Hex(&decoded.u_recipient).to_string()),
+        ],
+        LendergroupCall::LiquidateDefaultedLoanWithIncentive(decoded) => vec![
+            argument("bid_id", decoded.u_bid_id.to_string()),
+            argument("token_amount_difference", decoded.u_token_amount_difference.to_string()),
+        ],
+        LendergroupCall::PauseBorrowing | LendergroupCall::UnpauseBorrowing => vec![],
+        LendergroupCall::AcceptFundsForAcceptBid(decoded) => vec![
+            argument("borrower", Hex(&decoded.u_borrower).to_string()),
+            argument("bid_id", decoded.u_bid_id.to_string()),
+            argument("principal_amount", decoded.u_principal_amount.to_string()),
+            argument("collateral_amount", decoded.u_collateral_amount.to_string()),
+            argument("collateral_token_address", Hex(&decoded.u_collateral_token_address).to_string()),
+            argument("collateral_token_id", decoded.u_collateral_token_id.to_string()),
+            argument("loan_duration", decoded.u_loan_duration.to_string()),
+            argument("interest_rate", decoded.u_interest_rate.to_string()),
+        ],
+        LendergroupCall::RepayLoanCallback(decoded) => vec![
+            argument("bid_id", decoded.u_bid_id.to_string()),
+            argument("repayer", Hex(&decoded.repayer).to_string()),
+            argument("principal_amount", decoded.principal_amount.to_string()),
+            argument("interest_amount", decoded.interest_amount.to_string()),
+        ],
+    }
+}
+
+fn selector_label(data: &[u8]) -> String {
+    format!("0x{}", Hex(&data[..4]))
+}
+
+fn revert_reason(revert_data: &[u8]) -> Option<String> {
+    if revert_data.len() < 4 {
+        return None;
+    }
+    let (selector, data) = revert_data.split_at(4);
+    if selector == ERROR_SELECTOR {
+        if let Ok(tokens) = ethabi::decode(&[ethabi::ParamType::String], data) {
+            if let Some(ethabi::Token::String(message)) = tokens.into_iter().next() {
+                return Some(rpc::sanitize_string(message));
+            }
+        }
+    }
+    if selector == PANIC_SELECTOR {
+        if let Ok(tokens) = ethabi::decode(&[ethabi::ParamType::Uint(256)], data) {
+            if let Some(ethabi::Token::Uint(code)) = tokens.into_iter().next() {
+                return Some(format!("Panic(0x{:x})", code));
+            }
+        }
+    }
+    // the pool ABI declares no errors, custom errors are reported by selector
+    Some(format!("custom error {}", selector_label(revert_data)))
+}
+
+// walked with map_calls, so the block calls are only decoded once
+pub fn map_lendergroup_v1_reverted_call(
+    blk: &eth::Block,
+    trx: &eth::TransactionTrace,
+    call: &eth::Call,
+    decoded: Option<&LendergroupCall>,
+    factory: &contract::TrackedFactory,
+    reverted_calls: &mut Vec<contract::RevertedCall>,
+) {
+    // calls only reverted because their parent failed carry no revert reason of their own
+    if !call.status_failed {
+        return;
+    }
+    let (function, arguments) = match decoded {
+        Some(decoded) => (decoded.name().to_string(), call_arguments(decoded)),
+        None if call.input.len() >= 4 => (selector_label(&call.input), vec![]),
+        None => (String::new(), vec![]),
+    };
+    reverted_calls.push(contract::RevertedCall {
+        call_tx_hash: Hex(&trx.hash).to_string(),
+        call_block_time: blk.timestamp_seconds(),
+        call_block_number: blk.number,
+        call_ordinal: call.begin_ordinal,
+        call_address: Hex(&call.address).to_string(),
+        caller: call.caller.clone(),
+        tx_from: trx.from.clone(),
+        factory_address: factory.address.clone(),
+        factory_version: factory.version,
+        function,
+        arguments,
+        gas_used: call.gas_consumed,
+        revert_reason: revert_reason(&call.return_data),
+        revert_data: call.return_data.clone(),
+        failure_reason: call.failure_reason.clone(),
+    });
+}
+
+// arguments as a flat JSON object, values are decimal or hex strings so need no escaping
+fn arguments_json(arguments: &[contract::CallArgument]) -> String {
+    let fields: Vec<String> = arguments
+        .iter()
+        .map(|argument| format!("\"{}\":\"{}\"", argument.name, argument.value))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+pub fn db_reverted_calls_out(reverted_calls: &contract::RevertedCalls, tables: &mut DatabaseChangeTables) {
+    reverted_calls.calls.iter().for_each(|call| {
+        let row = tables
+            .create_row("reverted_call", [("call_tx_hash", call.call_tx_hash.to_string()),("call_ordinal", call.call_ordinal.to_string())])
+            .set("call_block_time", call.call_block_time)
+            .set("call_block_number", call.call_block_number)
+            .set("call_address", &call.call_address)
+            .set("caller", Hex(&call.caller).to_string())
+            .set("tx_from", Hex(&call.tx_from).to_string())
+            .set("factory_address", Hex(&call.factory_address).to_string())
+            .set("factory_version", factories::version_label(call.factory_version()).to_string())
+            .set("function", &call.function)
+            .set("arguments", arguments_json(&call.arguments))
+            .set("gas_used", call.gas_used)
+            .set("revert_data", Hex(&call.revert_data).to_string())
+            .set("failure_reason", &call.failure_reason);
+        if let Some(revert_reason) = &call.revert_reason {
+            row.set("revert_reason", revert_reason);
+        }
+    });
+}
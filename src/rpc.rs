@@ -1,75 +1,492 @@
 use crate::abi::lendergroup_contract::functions;
-use crate::{abi, eth };
+use crate::abi::{erc20, erc20_bytes32, teller_v2};
+use crate::pb::contract::v1 as contract;
+use contract::RpcEnrichmentStatus;
 use ethabi::Address;
 use ethabi::ethereum_types::H160;
-use prost::Message;
-
-use substreams::log;
 use substreams::scalar::BigInt;
-use substreams::Hex;
 use substreams_ethereum::rpc::RpcBatch;
+use substreams_ethereum::pb::eth::rpc::RpcResponse;
 
 
 /*
 
-Example 
+Example
 
 https://github.com/streamingfast/substreams-uniswap-v3/blob/develop/src/rpc.rs
 
 
 */
 
+// eth_calls made on every initialized pool, in batch order: 3 on the pool, then decimals() of its 3 tokens
+const POOL_INITIALIZATION_CALLS: usize = 6;
+
+// eth_calls made on every token (name, symbol), in batch order. decimals() is read with the pool initialization calls
+const TOKEN_METADATA_CALLS: usize = 2;
+
+// view functions called on every snapshotted pool, in batch order
+const POOL_ONCHAIN_STATE_CALLS: usize = 10;
+
+// snapshotted pools per RpcBatch, so an interval block over every pool sends batches of at most 200 eth_calls
+const POOL_ONCHAIN_STATE_BATCH_POOLS: usize = 20;
+
+// TellerV2 view functions called on every accepted bid (bids, calculateNextDueDate, bidDefaultDuration, bidPaymentCycleType)
+const TELLER_V2_BID_CALLS: usize = 4;
+
+// pool oracle view functions called on every accepted loan (getSqrtTwapX96 over the pool config's twap interval,
+// getAverageWeightedPriceForCollateralTokensPerPrincipalTokens, getCollateralRequiredForPrincipalAmount)
+const LOAN_ORACLE_CALLS: usize = 3;
+
+// each field is None when its eth_call failed (reverted, or the output did not decode)
 pub struct LenderGroupPoolInitializationDataFromRpc {
-        
-    pub teller_v2_address: Address,
-
-    pub uniswap_v3_pool_address: Address ,
-
-    pub smart_commitment_forwarder_address: Address 
-
-}
-
-
-pub fn fetch_lender_group_pool_initialization_data_from_rpc(pool_contract_address: &String) -> Option<LenderGroupPoolInitializationDataFromRpc> {
-        
-    let pool_contract_address_decoded = hex::decode(pool_contract_address).unwrap(); 
-        
-        
- 
-    
-    let teller_v2_function = abi::lendergroup_contract::functions::TellerV2 {};
-    let Some(teller_v2_address) = teller_v2_function.call(
-        pool_contract_address_decoded.clone()
-     ) else {return None};
-    
-        let uniswap_v3_pool_function = abi::lendergroup_contract::functions::UniswapV3Pool {};
-        let Some(uniswap_v3_pool_address) = uniswap_v3_pool_function.call(
-            pool_contract_address_decoded.clone()
-        ) else {return None};
-        
-    
-            
-        let smart_commitment_forwarder_function = abi::lendergroup_contract::functions::SmartCommitmentForwarder {};
-        let Some(smart_commitment_forwarder_address) = smart_commitment_forwarder_function.call(
-              pool_contract_address_decoded.clone()
-         ) else {return None};
-            
-            
-     
-    
-    return Some(  
-        LenderGroupPoolInitializationDataFromRpc{ 
-              
-              
-              
-            teller_v2_address: H160::from_slice(&teller_v2_address ) ,
-            uniswap_v3_pool_address: H160::from_slice(&uniswap_v3_pool_address ) ,
-            smart_commitment_forwarder_address: H160::from_slice(&smart_commitment_forwarder_address ) ,
-             
-            
 
+    pub teller_v2_address: Option<Address>,
+
+    pub uniswap_v3_pool_address: Option<Address>,
+
+    pub smart_commitment_forwarder_address: Option<Address>,
+
+    pub principal_token_decimals: Option<u64>,
+
+    pub collateral_token_decimals: Option<u64>,
+
+    pub pool_shares_token_decimals: Option<u64>,
+
+}
+
+impl LenderGroupPoolInitializationDataFromRpc {
+    // the token decimals only scale amounts, they do not count towards the pool enrichment
+    pub fn enrichment_status(&self) -> RpcEnrichmentStatus {
+        enrichment_status(&[
+            self.teller_v2_address.is_some(),
+            self.uniswap_v3_pool_address.is_some(),
+            self.smart_commitment_forwarder_address.is_some(),
+        ])
+    }
+}
+
+pub fn enrichment_status(fetched: &[bool]) -> RpcEnrichmentStatus {
+    if fetched.iter().all(|ok| *ok) {
+        RpcEnrichmentStatus::Complete
+    } else if fetched.iter().any(|ok| *ok) {
+        RpcEnrichmentStatus::Partial
+    } else {
+        RpcEnrichmentStatus::Failed
+    }
+}
+
+// responses of an RpcBatch, a failed batch leaves every field it fetches unset rather than halting the stream
+fn execute_batch(batch: RpcBatch, label: &str) -> Vec<RpcResponse> {
+    match batch.execute() {
+        Ok(responses) => responses.responses,
+        Err(err) => {
+            substreams::log::info!("{} RPC batch failed: {}", label, err);
+            vec![]
         }
-    ); 
- 
- 
-}
\ No newline at end of file
+    }
+}
+
+fn decode_bigint<F: substreams_ethereum::rpc::RPCDecodable<BigInt> + substreams_ethereum::Function>(
+    response: Option<&RpcResponse>,
+) -> Option<BigInt> {
+    response.and_then(RpcBatch::decode::<_, F>)
+}
+
+fn decode_decimals(response: Option<&RpcResponse>) -> Option<u64> {
+    response
+        .and_then(RpcBatch::decode::<_, erc20::functions::Decimals>)
+        .map(|decimals| decimals.to_u64())
+}
+
+fn decode_address<F: substreams_ethereum::rpc::RPCDecodable<Vec<u8>> + substreams_ethereum::Function>(
+    response: Option<&RpcResponse>,
+) -> Option<Address> {
+    response
+        .and_then(RpcBatch::decode::<_, F>)
+        .map(|address| H160::from_slice(&address))
+}
+
+
+// all the calls of all the pools go out in a single RpcBatch, results are in the order of pool_configs
+pub fn fetch_lender_group_pool_initialization_data_from_rpc(
+    pool_configs: &[contract::PoolConfig],
+) -> Vec<LenderGroupPoolInitializationDataFromRpc> {
+
+    if pool_configs.is_empty() {
+        return vec![];
+    }
+
+    // a pool whose address does not decode gets no calls and is left unenriched
+    let pool_contract_addresses: Vec<Option<Vec<u8>>> = pool_configs
+        .iter()
+        .map(|pool_config| match hex::decode(&pool_config.group_pool_address) {
+            Ok(pool_contract_address) => Some(pool_contract_address),
+            Err(_) => {
+                substreams::log::info!("Skipping pool initialization calls, invalid group pool address {}", pool_config.group_pool_address);
+                None
+            }
+        })
+        .collect();
+
+    let batch = pool_configs.iter().zip(pool_contract_addresses.iter()).fold(RpcBatch::new(), |batch, (pool_config, pool_contract_address)| {
+        let Some(pool_contract_address) = pool_contract_address.clone() else {
+            return batch;
+        };
+        batch
+            .add(functions::TellerV2 {}, pool_contract_address.clone())
+            .add(functions::UniswapV3Pool {}, pool_contract_address.clone())
+            .add(functions::SmartCommitmentForwarder {}, pool_contract_address)
+            .add(erc20::functions::Decimals {}, pool_config.principal_token_address.clone())
+            .add(erc20::functions::Decimals {}, pool_config.collateral_token_address.clone())
+            .add(erc20::functions::Decimals {}, pool_config.pool_shares_token.clone())
+    });
+
+    let responses = execute_batch(batch, "Pool initialization");
+
+    // position of each pool in the batch, skipped pools have none
+    let batch_positions = pool_contract_addresses.iter().scan(0, |next, pool_contract_address| {
+        let position = pool_contract_address.as_ref().map(|_| *next);
+        *next += usize::from(position.is_some());
+        Some(position)
+    });
+
+    batch_positions
+        .map(|position| {
+            let response = |call: usize| position.and_then(|position| responses.get(position * POOL_INITIALIZATION_CALLS + call));
+            LenderGroupPoolInitializationDataFromRpc {
+                teller_v2_address: decode_address::<functions::TellerV2>(response(0)),
+                uniswap_v3_pool_address: decode_address::<functions::UniswapV3Pool>(response(1)),
+                smart_commitment_forwarder_address: decode_address::<functions::SmartCommitmentForwarder>(response(2)),
+                principal_token_decimals: decode_decimals(response(3)),
+                collateral_token_decimals: decode_decimals(response(4)),
+                pool_shares_token_decimals: decode_decimals(response(5)),
+            }
+        })
+        .collect()
+}
+
+// fills the RPC fields of the pool configs initialized in this block
+pub fn enrich_pool_configs(pool_configs: &mut [contract::PoolConfig]) {
+    let fetched = fetch_lender_group_pool_initialization_data_from_rpc(pool_configs);
+
+    for (pool_config, fetched_rpc_data) in pool_configs.iter_mut().zip(fetched) {
+        pool_config.rpc_enrichment_status = fetched_rpc_data.enrichment_status() as i32;
+        pool_config.teller_v2_address = fetched_rpc_data.teller_v2_address.map(|address| address.to_fixed_bytes().to_vec());
+        pool_config.uniswap_v3_pool_address = fetched_rpc_data.uniswap_v3_pool_address.map(|address| address.to_fixed_bytes().to_vec());
+        pool_config.smart_commitment_forwarder_address = fetched_rpc_data
+            .smart_commitment_forwarder_address
+            .map(|address| address.to_fixed_bytes().to_vec());
+        pool_config.principal_token_decimals = fetched_rpc_data.principal_token_decimals;
+        pool_config.collateral_token_decimals = fetched_rpc_data.collateral_token_decimals;
+        pool_config.pool_shares_token_decimals = fetched_rpc_data.pool_shares_token_decimals;
+    }
+}
+
+// each field is None when its eth_call failed, name and symbol fall back to the bytes32 ABI
+pub struct TokenMetadataFromRpc {
+
+    pub name: Option<String>,
+
+    pub symbol: Option<String>,
+
+}
+
+impl TokenMetadataFromRpc {
+    pub fn enrichment_status(&self) -> RpcEnrichmentStatus {
+        enrichment_status(&[self.name.is_some(), self.symbol.is_some()])
+    }
+}
+
+// null bytes are not accepted by the sinks, and bytes32 values are right padded with them
+pub fn sanitize_string(value: String) -> String {
+    value.replace('\u{0}', "")
+}
+
+// string return value, or the bytes32 one of tokens predating the ERC20 metadata extension (MKR, SAI ...)
+fn decode_string_or_bytes32<S, B>(response: Option<&RpcResponse>) -> Option<String>
+where
+    S: substreams_ethereum::rpc::RPCDecodable<String>,
+    B: substreams_ethereum::rpc::RPCDecodable<[u8; 32]>,
+{
+    let response = response.filter(|response| !response.failed)?;
+    S::output(response.raw.as_ref())
+        .ok()
+        .or_else(|| {
+            B::output(response.raw.as_ref())
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes.to_vec()).ok())
+        })
+        .map(sanitize_string)
+}
+
+// all the calls of all the tokens go out in a single RpcBatch, results are in the order of token_addresses
+pub fn fetch_token_metadata_from_rpc(token_addresses: &[Vec<u8>]) -> Vec<TokenMetadataFromRpc> {
+
+    if token_addresses.is_empty() {
+        return vec![];
+    }
+
+    let batch = token_addresses.iter().fold(RpcBatch::new(), |batch, token_address| {
+        batch
+            .add(erc20::functions::Name {}, token_address.clone())
+            .add(erc20::functions::Symbol {}, token_address.clone())
+    });
+
+    let responses = execute_batch(batch, "Token metadata");
+
+    (0..token_addresses.len())
+        .map(|token| {
+            let response = |call: usize| responses.get(token * TOKEN_METADATA_CALLS + call);
+            TokenMetadataFromRpc {
+                name: decode_string_or_bytes32::<erc20::functions::Name, erc20_bytes32::functions::Name>(response(0)),
+                symbol: decode_string_or_bytes32::<erc20::functions::Symbol, erc20_bytes32::functions::Symbol>(response(1)),
+            }
+        })
+        .collect()
+}
+
+// each field is None when its eth_call failed (reverted, or the output did not decode)
+pub struct PoolOnchainStateFromRpc {
+
+    pub pool_utilization_ratio: Option<BigInt>,
+
+    pub shares_exchange_rate: Option<BigInt>,
+
+    pub principal_amount_available_to_borrow: Option<BigInt>,
+
+    pub total_principal_tokens_committed: Option<BigInt>,
+
+    pub total_principal_tokens_outstanding_in_active_loans: Option<BigInt>,
+
+    pub total_principal_tokens_lended: Option<BigInt>,
+
+    pub total_principal_tokens_repaid: Option<BigInt>,
+
+    pub total_interest_collected: Option<BigInt>,
+
+    pub total_collateral_tokens_escrowed_for_loans: Option<BigInt>,
+
+    pub paused: Option<bool>,
+
+}
+
+impl PoolOnchainStateFromRpc {
+    pub fn enrichment_status(&self) -> RpcEnrichmentStatus {
+        enrichment_status(&[
+            self.pool_utilization_ratio.is_some(),
+            self.shares_exchange_rate.is_some(),
+            self.principal_amount_available_to_borrow.is_some(),
+            self.total_principal_tokens_committed.is_some(),
+            self.total_principal_tokens_outstanding_in_active_loans.is_some(),
+            self.total_principal_tokens_lended.is_some(),
+            self.total_principal_tokens_repaid.is_some(),
+            self.total_interest_collected.is_some(),
+            self.total_collateral_tokens_escrowed_for_loans.is_some(),
+            self.paused.is_some(),
+        ])
+    }
+}
+
+// the view calls go out in one RpcBatch per chunk of pools, results are in the order of pool_contract_addresses
+pub fn fetch_pool_onchain_state_from_rpc(pool_contract_addresses: &[Vec<u8>]) -> Vec<PoolOnchainStateFromRpc> {
+    pool_contract_addresses
+        .chunks(POOL_ONCHAIN_STATE_BATCH_POOLS)
+        .flat_map(fetch_pool_onchain_state_batch)
+        .collect()
+}
+
+// one RpcBatch for a chunk of pools, results are in the order of pool_contract_addresses
+fn fetch_pool_onchain_state_batch(pool_contract_addresses: &[Vec<u8>]) -> Vec<PoolOnchainStateFromRpc> {
+    let batch = pool_contract_addresses.iter().fold(RpcBatch::new(), |batch, pool_contract_address| {
+        batch
+            .add(functions::GetPoolUtilizationRatio {}, pool_contract_address.clone())
+            .add(functions::SharesExchangeRate {}, pool_contract_address.clone())
+            .add(functions::GetPrincipalAmountAvailableToBorrow {}, pool_contract_address.clone())
+            .add(functions::TotalPrincipalTokensCommitted {}, pool_contract_address.clone())
+            .add(functions::GetTotalPrincipalTokensOutstandingInActiveLoans {}, pool_contract_address.clone())
+            .add(functions::TotalPrincipalTokensLended {}, pool_contract_address.clone())
+            .add(functions::TotalPrincipalTokensRepaid {}, pool_contract_address.clone())
+            .add(functions::TotalInterestCollected {}, pool_contract_address.clone())
+            .add(functions::TotalCollateralTokensEscrowedForLoans {}, pool_contract_address.clone())
+            .add(functions::Paused {}, pool_contract_address.clone())
+    });
+
+    let responses = execute_batch(batch, "Pool onchain state");
+
+    (0..pool_contract_addresses.len())
+        .map(|pool| {
+            let response = |call: usize| responses.get(pool * POOL_ONCHAIN_STATE_CALLS + call);
+            PoolOnchainStateFromRpc {
+                pool_utilization_ratio: decode_bigint::<functions::GetPoolUtilizationRatio>(response(0)),
+                shares_exchange_rate: decode_bigint::<functions::SharesExchangeRate>(response(1)),
+                principal_amount_available_to_borrow: decode_bigint::<functions::GetPrincipalAmountAvailableToBorrow>(
+                    response(2),
+                ),
+                total_principal_tokens_committed: decode_bigint::<functions::TotalPrincipalTokensCommitted>(response(3)),
+                total_principal_tokens_outstanding_in_active_loans: decode_bigint::<
+                    functions::GetTotalPrincipalTokensOutstandingInActiveLoans,
+                >(response(4)),
+                total_principal_tokens_lended: decode_bigint::<functions::TotalPrincipalTokensLended>(response(5)),
+                total_principal_tokens_repaid: decode_bigint::<functions::TotalPrincipalTokensRepaid>(response(6)),
+                total_interest_collected: decode_bigint::<functions::TotalInterestCollected>(response(7)),
+                total_collateral_tokens_escrowed_for_loans: decode_bigint::<functions::TotalCollateralTokensEscrowedForLoans>(
+                    response(8),
+                ),
+                paused: response(9).and_then(RpcBatch::decode::<_, functions::Paused>),
+            }
+        })
+        .collect()
+}
+
+// bids(bid id) fields kept on the loans, the rest of the struct is already on BorrowerAcceptedFunds
+pub struct TellerV2BidTermsFromRpc {
+
+    pub market_id: BigInt,
+
+    pub accepted_timestamp: u64,
+
+    pub loan_duration: u64,
+
+    pub payment_cycle_amount: BigInt,
+
+    pub payment_cycle: u64,
+
+    pub apr: u64,
+
+    pub payment_type: u64,
+
+}
+
+// each field is None when its eth_call failed (reverted, or the output did not decode)
+pub struct TellerV2BidFromRpc {
+
+    pub terms: Option<TellerV2BidTermsFromRpc>,
+
+    pub next_due_date: Option<u64>,
+
+    pub default_duration: Option<u64>,
+
+    pub payment_cycle_type: Option<u64>,
+
+}
+
+impl TellerV2BidFromRpc {
+    pub fn enrichment_status(&self) -> RpcEnrichmentStatus {
+        enrichment_status(&[
+            self.terms.is_some(),
+            self.next_due_date.is_some(),
+            self.default_duration.is_some(),
+            self.payment_cycle_type.is_some(),
+        ])
+    }
+}
+
+fn decode_bid_terms(response: Option<&RpcResponse>) -> Option<TellerV2BidTermsFromRpc> {
+    let (_, _, _, market_id, _, loan_details, terms, _, payment_type) =
+        response.and_then(RpcBatch::decode::<_, teller_v2::functions::Bids>)?;
+    let (_, _, _, _, accepted_timestamp, _, loan_duration) = loan_details;
+    let (payment_cycle_amount, payment_cycle, apr) = terms;
+    Some(TellerV2BidTermsFromRpc {
+        market_id,
+        accepted_timestamp: accepted_timestamp.to_u64(),
+        loan_duration: loan_duration.to_u64(),
+        payment_cycle_amount,
+        payment_cycle: payment_cycle.to_u64(),
+        apr: apr.to_u64(),
+        payment_type: payment_type.to_u64(),
+    })
+}
+
+// all the calls of all the bids go out in a single RpcBatch, results are in the order of bids (TellerV2 address, bid id)
+pub fn fetch_teller_v2_bids_from_rpc(bids: &[(Vec<u8>, BigInt)]) -> Vec<TellerV2BidFromRpc> {
+
+    if bids.is_empty() {
+        return vec![];
+    }
+
+    let batch = bids.iter().fold(RpcBatch::new(), |batch, (teller_v2_address, bid_id)| {
+        batch
+            .add(teller_v2::functions::Bids { param0: bid_id.clone() }, teller_v2_address.clone())
+            .add(teller_v2::functions::CalculateNextDueDate { u_bid_id: bid_id.clone() }, teller_v2_address.clone())
+            .add(teller_v2::functions::BidDefaultDuration { param0: bid_id.clone() }, teller_v2_address.clone())
+            .add(teller_v2::functions::BidPaymentCycleType { param0: bid_id.clone() }, teller_v2_address.clone())
+    });
+
+    let responses = execute_batch(batch, "TellerV2 bid");
+
+    (0..bids.len())
+        .map(|bid| {
+            let response = |call: usize| responses.get(bid * TELLER_V2_BID_CALLS + call);
+            TellerV2BidFromRpc {
+                terms: decode_bid_terms(response(0)),
+                next_due_date: decode_bigint::<teller_v2::functions::CalculateNextDueDate>(response(1))
+                    .map(|due_date| due_date.to_u64()),
+                default_duration: decode_bigint::<teller_v2::functions::BidDefaultDuration>(response(2))
+                    .map(|duration| duration.to_u64()),
+                payment_cycle_type: decode_bigint::<teller_v2::functions::BidPaymentCycleType>(response(3))
+                    .map(|cycle_type| cycle_type.to_u64()),
+            }
+        })
+        .collect()
+}
+
+// each field is None when its eth_call failed (reverted, or the output did not decode)
+pub struct LoanOracleDataFromRpc {
+
+    pub sqrt_twap_x96: Option<BigInt>,
+
+    pub average_weighted_price: Option<BigInt>,
+
+    pub required_collateral_amount: Option<BigInt>,
+
+}
+
+impl LoanOracleDataFromRpc {
+    pub fn enrichment_status(&self) -> RpcEnrichmentStatus {
+        enrichment_status(&[
+            self.sqrt_twap_x96.is_some(),
+            self.average_weighted_price.is_some(),
+            self.required_collateral_amount.is_some(),
+        ])
+    }
+}
+
+// one RpcBatch for all the loans (group pool address, principal amount, twap interval), results are in the order of loans
+pub fn fetch_loan_oracle_data_from_rpc(loans: &[(Vec<u8>, BigInt, u64)]) -> Vec<LoanOracleDataFromRpc> {
+
+    if loans.is_empty() {
+        return vec![];
+    }
+
+    let batch = loans.iter().fold(RpcBatch::new(), |batch, (pool_contract_address, principal_amount, twap_interval)| {
+        batch
+            .add(
+                functions::GetSqrtTwapX96 {
+                    twap_interval: BigInt::from(*twap_interval),
+                },
+                pool_contract_address.clone(),
+            )
+            .add(functions::GetAverageWeightedPriceForCollateralTokensPerPrincipalTokens {}, pool_contract_address.clone())
+            .add(
+                functions::GetCollateralRequiredForPrincipalAmount {
+                    u_principal_amount: principal_amount.clone(),
+                },
+                pool_contract_address.clone(),
+            )
+    });
+
+    let responses = execute_batch(batch, "Loan oracle");
+
+    (0..loans.len())
+        .map(|loan| {
+            let response = |call: usize| responses.get(loan * LOAN_ORACLE_CALLS + call);
+            LoanOracleDataFromRpc {
+                sqrt_twap_x96: decode_bigint::<functions::GetSqrtTwapX96>(response(0)),
+                average_weighted_price: decode_bigint::<functions::GetAverageWeightedPriceForCollateralTokensPerPrincipalTokens>(
+                    response(1),
+                ),
+                required_collateral_amount: decode_bigint::<functions::GetCollateralRequiredForPrincipalAmount>(response(2)),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+use crate::abi::lendergroup_contract::functions;
+use crate::abi::teller_v2;
+use crate::pb::contract::v1 as contract;
+use contract::{RpcEnrichmentStatus, TellerV2PaymentCycleType};
+use substreams_ethereum::Function;
+
+pub fn status_label(status: RpcEnrichmentStatus) -> &'static str {
+    match status {
+        RpcEnrichmentStatus::Complete => "complete",
+        RpcEnrichmentStatus::Partial => "partial",
+        RpcEnrichmentStatus::Failed => "failed",
+        RpcEnrichmentStatus::Unspecified => "unspecified",
+    }
+}
+
+// one failure per RPC field left unset on the enriched events of the block
+pub fn map_rpc_failures(events: &contract::Events) -> contract::RpcFailures {
+    let mut failures = contract::RpcFailures::default();
+
+    events.lendergroup_pool_initializeds.iter().for_each(|evt| {
+        for (method, fetched) in [
+            (functions::TellerV2::NAME, evt.teller_v2_address.is_some()),
+            (functions::UniswapV3Pool::NAME, evt.uniswap_v3_pool_address.is_some()),
+            (functions::SmartCommitmentForwarder::NAME, evt.smart_commitment_forwarder_address.is_some()),
+        ] {
+            if fetched {
+                continue;
+            }
+            substreams::log::info!("RPC call {} failed on group pool {} at block {}", method, evt.evt_address, evt.evt_block_number);
+            failures.failures.push(contract::RpcFailure {
+                evt_tx_hash: evt.evt_tx_hash.clone(),
+                evt_index: evt.evt_index,
+                evt_block_time: evt.evt_block_time,
+                evt_block_number: evt.evt_block_number,
+                contract_address: evt.evt_address.clone(),
+                method: method.to_string(),
+            });
+        }
+    });
+
+    // TellerV2 and pool oracle calls of the accepted loans, all reported against the group pool
+    events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
+        let mut calls = vec![];
+        if let Some(bid) = &evt.teller_v2_bid {
+            calls.extend([
+                (teller_v2::functions::Bids::NAME, bid.market_id.is_some()),
+                (teller_v2::functions::CalculateNextDueDate::NAME, bid.first_payment_due_date.is_some()),
+                (teller_v2::functions::BidDefaultDuration::NAME, bid.default_duration.is_some()),
+                (teller_v2::functions::BidPaymentCycleType::NAME, bid.payment_cycle_type() != TellerV2PaymentCycleType::Unspecified),
+            ]);
+        }
+        if let Some(audit) = &evt.collateral_audit {
+            calls.extend([
+                (functions::GetSqrtTwapX96::NAME, audit.sqrt_twap_x96.is_some()),
+                (
+                    functions::GetAverageWeightedPriceForCollateralTokensPerPrincipalTokens::NAME,
+                    audit.average_weighted_price.is_some(),
+                ),
+                (functions::GetCollateralRequiredForPrincipalAmount::NAME, audit.required_collateral_amount.is_some()),
+            ]);
+        }
+        for (method, fetched) in calls {
+            if fetched {
+                continue;
+            }
+            substreams::log::info!("RPC call {} failed on bid {} of group pool {} at block {}", method, evt.bid_id, evt.evt_address, evt.evt_block_number);
+            failures.failures.push(contract::RpcFailure {
+                evt_tx_hash: evt.evt_tx_hash.clone(),
+                evt_index: evt.evt_index,
+                evt_block_time: evt.evt_block_time,
+                evt_block_number: evt.evt_block_number,
+                contract_address: evt.evt_address.clone(),
+                method: method.to_string(),
+            });
+        }
+    });
+
+    failures
+}
@@ -0,0 +1,135 @@
+use crate::abi;
+use crate::amounts;
+use crate::pb::contract::v1 as contract;
+use std::str::FromStr;
+use substreams::key;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt};
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+pub const BALANCE: &str = "balance";
+
+// mints come from and burns go to the zero address, which is not a holder
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+// store_share_tokens key, the shares token address (the value is its group pool address)
+pub fn share_token_key(token_address: &[u8]) -> String {
+    Hex(token_address).to_string()
+}
+
+// share_holder:{group pool address}:{holder address}:{total}
+pub fn share_holder_key(pool_address: &str, holder_address: &str, total: &str) -> String {
+    format!("share_holder:{}:{}:{}", pool_address, holder_address, total)
+}
+
+pub fn map_share_transfer_log(
+    blk: &eth::Block,
+    trx: &eth::TransactionTrace,
+    log: &eth::Log,
+    pool_address: &str,
+    pool_config: &contract::PoolConfig,
+    events: &mut contract::Events,
+) {
+    if let Some(event) = abi::erc20::events::Transfer::match_and_decode(log) {
+        events.share_transfers.push(contract::ShareTransfer {
+            evt_tx_hash: Hex(&trx.hash).to_string(),
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            evt_block_time: blk.timestamp_seconds(),
+            evt_block_number: blk.number,
+            evt_address: Hex(&log.address).to_string(),
+            group_pool_address: pool_address.to_string(),
+            from: event.from,
+            to: event.to,
+            value: event.value.to_string(),
+            value_normalized: amounts::normalized_amount(&event.value.to_string(), pool_config.pool_shares_token_decimals),
+        });
+    }
+}
+
+pub fn add_share_balances(events: &contract::Events, store: &StoreAddBigInt) {
+    events.share_transfers.iter().for_each(|evt| {
+        let value = BigInt::from_str(&evt.value).unwrap();
+        if evt.from != ZERO_ADDRESS {
+            store.add(
+                evt.evt_ordinal,
+                share_holder_key(&evt.group_pool_address, &Hex(&evt.from).to_string(), BALANCE),
+                BigInt::zero() - value.clone(),
+            );
+        }
+        if evt.to != ZERO_ADDRESS {
+            store.add(
+                evt.evt_ordinal,
+                share_holder_key(&evt.group_pool_address, &Hex(&evt.to).to_string(), BALANCE),
+                value,
+            );
+        }
+    });
+}
+
+pub fn db_shares_out(events: &contract::Events, share_balances_deltas: &Deltas<DeltaBigInt>, tables: &mut DatabaseChangeTables) {
+    events.share_transfers.iter().for_each(|evt| {
+        let row = tables
+            .create_row("share_transfer", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("from", Hex(&evt.from).to_string())
+            .set("to", Hex(&evt.to).to_string())
+            .set("value", BigDecimal::from_str(&evt.value).unwrap());
+        if let Some(value_normalized) = &evt.value_normalized {
+            row.set("value_normalized", BigDecimal::from_str(value_normalized).unwrap());
+        }
+    });
+    share_balances_deltas.deltas.iter().for_each(|delta| {
+        let pool_address = key::segment_at(&delta.key, 1);
+        let holder_address = key::segment_at(&delta.key, 2);
+        tables
+            .upsert_row(
+                "share_holder",
+                [
+                    ("group_pool_address", pool_address.to_string()),
+                    ("holder_address", holder_address.to_string()),
+                ],
+            )
+            .set(key::last_segment(&delta.key), delta.new_value.clone());
+    });
+}
+
+pub fn graph_shares_out(events: &contract::Events, share_balances_deltas: &Deltas<DeltaBigInt>, tables: &mut EntityChangesTables) {
+    events.share_transfers.iter().for_each(|evt| {
+        let row = tables
+            .create_row("share_transfer", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("from", Hex(&evt.from).to_string())
+            .set("to", Hex(&evt.to).to_string())
+            .set("value", BigInt::from_str(&evt.value).unwrap());
+        if let Some(value_normalized) = &evt.value_normalized {
+            row.set("value_normalized", BigDecimal::from_str(value_normalized).unwrap());
+        }
+    });
+    share_balances_deltas.deltas.iter().for_each(|delta| {
+        let pool_address = key::segment_at(&delta.key, 1);
+        let holder_address = key::segment_at(&delta.key, 2);
+        let id = format!("{}-{}", pool_address, holder_address);
+        // the holder's first transfer creates its balance
+        let row = match delta.operation {
+            Operation::Create => tables.create_row("share_holder", id),
+            _ => tables.update_row("share_holder", id),
+        };
+        row.set("group_pool_address", pool_address.to_string())
+            .set("holder_address", holder_address.to_string())
+            .set(key::last_segment(&delta.key), delta.new_value.clone());
+    });
+}
@@ -0,0 +1,197 @@
+use crate::pb::contract::v1 as contract;
+use crate::rpc;
+use crate::rpc_failures::status_label;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use substreams::errors::Error;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::store::{DeltaProto, Deltas, StoreAppend, StoreGet, StoreGetArray};
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+/*
+
+`map_pool_onchain_snapshots` params: the number of blocks between two snapshots of every
+tracked pool, e.g. `1000`. Pools touched by an event are snapshotted on that block anyway,
+`0` only keeps those.
+
+*/
+
+// store_pools key holding every initialized group pool address
+pub const POOLS_KEY: &str = "pools";
+
+pub fn parse_snapshot_interval(params: &str) -> Result<u64, Error> {
+    params
+        .trim()
+        .parse::<u64>()
+        .map_err(|e| Error::msg(format!("invalid snapshot interval `{}` in params: {}", params, e)))
+}
+
+// a pool is appended once, when its config is first created in store_pool_configs
+pub fn append_pools(pool_configs_deltas: &Deltas<DeltaProto<contract::PoolConfig>>, store: &StoreAppend<String>) {
+    pool_configs_deltas
+        .deltas
+        .iter()
+        .filter(|delta| delta.operation == Operation::Create)
+        .for_each(|delta| {
+            store.append(delta.ordinal, POOLS_KEY, delta.key.clone());
+        });
+}
+
+// pools with any event (or shares token transfer) in this block
+fn touched_pools(events: &contract::Events) -> BTreeSet<String> {
+    let mut pools = BTreeSet::new();
+    pools.extend(events.lendergroup_borrower_accepted_funds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_defaulted_loan_liquidateds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_earnings_withdrawns.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_initializeds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_lender_added_principals.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_loan_repaids.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_ownership_transferreds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_pauseds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_pool_initializeds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.lendergroup_unpauseds.iter().map(|evt| evt.evt_address.clone()));
+    pools.extend(events.share_transfers.iter().map(|evt| evt.group_pool_address.clone()));
+    pools
+}
+
+pub fn map_pool_onchain_snapshots(
+    snapshot_interval: u64,
+    block_number: u64,
+    block_time: u64,
+    events: &contract::Events,
+    pools: &StoreGetArray<String>,
+) -> contract::PoolOnchainSnapshots {
+    let mut pool_addresses = touched_pools(events);
+    if snapshot_interval > 0 && block_number % snapshot_interval == 0 {
+        pool_addresses.extend(pools.get_last(POOLS_KEY).unwrap_or_default());
+    }
+    let (pool_addresses, pool_contract_addresses): (Vec<String>, Vec<Vec<u8>>) = pool_addresses
+        .into_iter()
+        .filter_map(|pool_address| match hex::decode(&pool_address) {
+            Ok(pool_contract_address) => Some((pool_address, pool_contract_address)),
+            Err(_) => {
+                substreams::log::info!("Skipping snapshot, invalid group pool address {}", pool_address);
+                None
+            }
+        })
+        .unzip();
+    let fetched = rpc::fetch_pool_onchain_state_from_rpc(&pool_contract_addresses);
+
+    contract::PoolOnchainSnapshots {
+        snapshots: pool_addresses
+            .into_iter()
+            .zip(fetched)
+            .map(|(pool_address, state)| contract::PoolOnchainSnapshot {
+                group_pool_address: pool_address,
+                block_number,
+                block_time,
+                rpc_enrichment_status: state.enrichment_status() as i32,
+                // a ratio that does not fit a u64 is left unset like a failed call
+                pool_utilization_ratio: state
+                    .pool_utilization_ratio
+                    .and_then(|ratio| ratio.to_string().parse::<u64>().ok()),
+                shares_exchange_rate: state.shares_exchange_rate.map(|value| value.to_string()),
+                principal_amount_available_to_borrow: state.principal_amount_available_to_borrow.map(|value| value.to_string()),
+                total_principal_tokens_committed: state.total_principal_tokens_committed.map(|value| value.to_string()),
+                total_principal_tokens_outstanding_in_active_loans: state
+                    .total_principal_tokens_outstanding_in_active_loans
+                    .map(|value| value.to_string()),
+                total_principal_tokens_lended: state.total_principal_tokens_lended.map(|value| value.to_string()),
+                total_principal_tokens_repaid: state.total_principal_tokens_repaid.map(|value| value.to_string()),
+                total_interest_collected: state.total_interest_collected.map(|value| value.to_string()),
+                total_collateral_tokens_escrowed_for_loans: state
+                    .total_collateral_tokens_escrowed_for_loans
+                    .map(|value| value.to_string()),
+                paused: state.paused,
+            })
+            .collect(),
+    }
+}
+
+fn amounts(snapshot: &contract::PoolOnchainSnapshot) -> [(&'static str, &Option<String>); 8] {
+    [
+        ("shares_exchange_rate", &snapshot.shares_exchange_rate),
+        ("principal_amount_available_to_borrow", &snapshot.principal_amount_available_to_borrow),
+        ("total_principal_tokens_committed", &snapshot.total_principal_tokens_committed),
+        (
+            "total_principal_tokens_outstanding_in_active_loans",
+            &snapshot.total_principal_tokens_outstanding_in_active_loans,
+        ),
+        ("total_principal_tokens_lended", &snapshot.total_principal_tokens_lended),
+        ("total_principal_tokens_repaid", &snapshot.total_principal_tokens_repaid),
+        ("total_interest_collected", &snapshot.total_interest_collected),
+        ("total_collateral_tokens_escrowed_for_loans", &snapshot.total_collateral_tokens_escrowed_for_loans),
+    ]
+}
+
+pub fn db_pool_onchain_snapshots_out(snapshots: &contract::PoolOnchainSnapshots, tables: &mut DatabaseChangeTables) {
+    snapshots.snapshots.iter().for_each(|snapshot| {
+        let row = tables
+            .create_row(
+                "pool_onchain_snapshot",
+                [
+                    ("group_pool_address", snapshot.group_pool_address.to_string()),
+                    ("block_number", snapshot.block_number.to_string()),
+                ],
+            )
+            .set("block_time", snapshot.block_time)
+            .set("rpc_enrichment_status", status_label(snapshot.rpc_enrichment_status()).to_string());
+        // a failed eth_call, or an amount that doesn't parse, leaves its column unset
+        if let Some(pool_utilization_ratio) = snapshot.pool_utilization_ratio {
+            row.set("pool_utilization_ratio", pool_utilization_ratio);
+        }
+        if let Some(paused) = snapshot.paused {
+            row.set("paused", paused);
+        }
+        for (column, amount) in amounts(snapshot) {
+            if let Some(Ok(amount)) = amount.as_deref().map(BigDecimal::from_str) {
+                row.set(column, amount);
+            }
+        }
+    });
+}
+
+pub fn graph_pool_onchain_snapshots_out(snapshots: &contract::PoolOnchainSnapshots, tables: &mut EntityChangesTables) {
+    snapshots.snapshots.iter().for_each(|snapshot| {
+        let row = tables
+            .create_row("pool_onchain_snapshot", format!("{}-{}", snapshot.group_pool_address, snapshot.block_number))
+            .set("group_pool_address", &snapshot.group_pool_address)
+            .set("block_number", BigInt::from(snapshot.block_number))
+            .set("block_time", BigInt::from(snapshot.block_time))
+            .set("rpc_enrichment_status", status_label(snapshot.rpc_enrichment_status()).to_string());
+        // a failed eth_call, or an amount that doesn't parse, leaves its field unset
+        if let Some(pool_utilization_ratio) = snapshot.pool_utilization_ratio {
+            row.set("pool_utilization_ratio", pool_utilization_ratio);
+        }
+        if let Some(paused) = snapshot.paused {
+            row.set("paused", paused);
+        }
+        for (field, amount) in amounts(snapshot) {
+            if let Some(Ok(amount)) = amount.as_deref().map(BigInt::from_str) {
+                row.set(field, amount);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_snapshot_interval() {
+        assert_eq!(parse_snapshot_interval("1000").unwrap(), 1000);
+        assert_eq!(parse_snapshot_interval(" 500\n").unwrap(), 500);
+        assert_eq!(parse_snapshot_interval("0").unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_empty_or_invalid_snapshot_interval() {
+        assert!(parse_snapshot_interval("").is_err());
+        assert!(parse_snapshot_interval("  ").is_err());
+        assert!(parse_snapshot_interval("-1").is_err());
+        assert!(parse_snapshot_interval("1e3").is_err());
+    }
+}
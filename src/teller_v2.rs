@@ -0,0 +1,520 @@
+use crate::abi::teller_v2::events;
+use crate::pb::contract::v1 as contract;
+use crate::rpc;
+use crate::rpc_failures;
+use crate::topics;
+use contract::{TellerV2PaymentCycleType, TellerV2PaymentType};
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{StoreGet, StoreGetProto, StoreGetString, StoreSet, StoreSetProto, StoreSetString};
+use substreams::Hex;
+use substreams_database_change::tables::{Row as DatabaseChangeRow, Tables as DatabaseChangeTables};
+use substreams_entity_change::tables::{Row as EntityChangesRow, Tables as EntityChangesTables};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+// store_teller_v2_bids key of a TellerV2 contract used by a tracked pool (the value is the first pool seen with it)
+pub fn teller_key(teller_address: &[u8]) -> String {
+    format!("teller:{}", Hex(teller_address))
+}
+
+// store_teller_v2_bids key of a bid accepted by a tracked pool, the value is the group pool address.
+// bid ids are only unique within a TellerV2 contract
+pub fn teller_bid_key(teller_address: &[u8], bid_id: &str) -> String {
+    format!("bid:{}:{}", Hex(teller_address), bid_id)
+}
+
+pub fn set_teller_v2_bids(
+    events: &contract::Events,
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+    store: &StoreSetString,
+) {
+    events.lendergroup_pool_initializeds.iter().for_each(|evt| {
+        if let Some(teller_v2_address) = &evt.teller_v2_address {
+            store.set(evt.evt_ordinal, teller_key(teller_v2_address), &evt.evt_address);
+        }
+    });
+    events.lendergroup_borrower_accepted_funds.iter().for_each(|evt| {
+        let teller_v2_address = pool_configs
+            .get_last(&evt.evt_address)
+            .and_then(|pool_config| pool_config.teller_v2_address);
+        if let Some(teller_v2_address) = teller_v2_address {
+            store.set(evt.evt_ordinal, teller_bid_key(&teller_v2_address, &evt.bid_id), &evt.evt_address);
+        }
+    });
+}
+
+pub fn payment_type_label(payment_type: TellerV2PaymentType) -> &'static str {
+    match payment_type {
+        TellerV2PaymentType::Emi => "emi",
+        TellerV2PaymentType::Bullet => "bullet",
+        TellerV2PaymentType::Unspecified => "unspecified",
+    }
+}
+
+pub fn payment_cycle_type_label(payment_cycle_type: TellerV2PaymentCycleType) -> &'static str {
+    match payment_cycle_type {
+        TellerV2PaymentCycleType::Seconds => "seconds",
+        TellerV2PaymentCycleType::Monthly => "monthly",
+        TellerV2PaymentCycleType::Unspecified => "unspecified",
+    }
+}
+
+fn bid_details(fetched: rpc::TellerV2BidFromRpc) -> contract::TellerV2BidDetails {
+    let rpc_enrichment_status = fetched.enrichment_status() as i32;
+    // the TellerV2 enums start at 0, ours keep 0 for a failed call
+    let payment_type = fetched
+        .terms
+        .as_ref()
+        .and_then(|terms| TellerV2PaymentType::from_i32(terms.payment_type as i32 + 1))
+        .unwrap_or(TellerV2PaymentType::Unspecified);
+    let payment_cycle_type = fetched
+        .payment_cycle_type
+        .and_then(|cycle_type| TellerV2PaymentCycleType::from_i32(cycle_type as i32 + 1))
+        .unwrap_or(TellerV2PaymentCycleType::Unspecified);
+    contract::TellerV2BidDetails {
+        market_id: fetched.terms.as_ref().map(|terms| terms.market_id.to_string()),
+        payment_cycle_amount: fetched.terms.as_ref().map(|terms| terms.payment_cycle_amount.to_string()),
+        payment_cycle: fetched.terms.as_ref().map(|terms| terms.payment_cycle),
+        apr: fetched.terms.as_ref().map(|terms| terms.apr),
+        payment_type: payment_type as i32,
+        accepted_timestamp: fetched.terms.as_ref().map(|terms| terms.accepted_timestamp),
+        loan_due_date: fetched.terms.as_ref().map(|terms| terms.accepted_timestamp + terms.loan_duration),
+        first_payment_due_date: fetched.next_due_date,
+        default_duration: fetched.default_duration,
+        payment_cycle_type: payment_cycle_type as i32,
+        rpc_enrichment_status,
+    }
+}
+
+// attaches the TellerV2 bid of every loan accepted in the block, in one RpcBatch.
+// Loans of a pool whose teller_v2() call failed keep teller_v2_bid unset
+pub fn enrich_accepted_bids(
+    accepted_funds: &mut [contract::LendergroupBorrowerAcceptedFunds],
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+) {
+    let (indexes, bids): (Vec<usize>, Vec<(Vec<u8>, BigInt)>) = accepted_funds
+        .iter()
+        .enumerate()
+        .filter_map(|(index, evt)| {
+            let teller_v2_address = pool_configs.get_last(&evt.evt_address)?.teller_v2_address?;
+            Some((index, (teller_v2_address, BigInt::from_str(&evt.bid_id).unwrap())))
+        })
+        .unzip();
+
+    let fetched = rpc::fetch_teller_v2_bids_from_rpc(&bids);
+
+    for (index, fetched_rpc_data) in indexes.into_iter().zip(fetched) {
+        accepted_funds[index].teller_v2_bid = Some(bid_details(fetched_rpc_data));
+    }
+}
+
+pub fn db_bid_details(bid: &contract::TellerV2BidDetails, row: &mut DatabaseChangeRow) {
+    row.set("payment_type", payment_type_label(bid.payment_type()).to_string())
+        .set("payment_cycle_type", payment_cycle_type_label(bid.payment_cycle_type()).to_string())
+        .set("bid_rpc_enrichment_status", rpc_failures::status_label(bid.rpc_enrichment_status()).to_string());
+    for (column, amount) in [("market_id", &bid.market_id), ("payment_cycle_amount", &bid.payment_cycle_amount)] {
+        if let Some(amount) = amount {
+            row.set(column, BigDecimal::from_str(amount).unwrap());
+        }
+    }
+    for (column, value) in [
+        ("payment_cycle", bid.payment_cycle),
+        ("apr", bid.apr),
+        ("bid_accepted_at", bid.accepted_timestamp),
+        ("loan_due_date", bid.loan_due_date),
+        ("first_payment_due_date", bid.first_payment_due_date),
+        ("default_duration", bid.default_duration),
+    ] {
+        if let Some(value) = value {
+            row.set(column, value);
+        }
+    }
+}
+
+pub fn graph_bid_details(bid: &contract::TellerV2BidDetails, row: &mut EntityChangesRow) {
+    row.set("payment_type", payment_type_label(bid.payment_type()).to_string())
+        .set("payment_cycle_type", payment_cycle_type_label(bid.payment_cycle_type()).to_string())
+        .set("bid_rpc_enrichment_status", rpc_failures::status_label(bid.rpc_enrichment_status()).to_string());
+    for (column, amount) in [("market_id", &bid.market_id), ("payment_cycle_amount", &bid.payment_cycle_amount)] {
+        if let Some(amount) = amount {
+            row.set(column, BigInt::from_str(amount).unwrap());
+        }
+    }
+    for (column, value) in [("payment_cycle", bid.payment_cycle), ("apr", bid.apr), ("default_duration", bid.default_duration)] {
+        if let Some(value) = value {
+            row.set(column, value);
+        }
+    }
+    for (column, timestamp) in [
+        ("bid_accepted_at", bid.accepted_timestamp),
+        ("loan_due_date", bid.loan_due_date),
+        ("first_payment_due_date", bid.first_payment_due_date),
+    ] {
+        if let Some(timestamp) = timestamp {
+            row.set(column, BigInt::from(timestamp));
+        }
+    }
+}
+
+// the SubmittedBid of a log, group_pool_address is left empty until the bid is accepted by a tracked pool
+fn submitted_bid(blk: &eth::Block, tx_hash: &[u8], log: &eth::Log) -> Option<contract::TellerV2SubmittedBid> {
+    let event = events::SubmittedBid::match_and_decode(log)?;
+    Some(contract::TellerV2SubmittedBid {
+        evt_tx_hash: Hex(tx_hash).to_string(),
+        evt_index: log.block_index,
+        evt_ordinal: log.ordinal,
+        evt_block_time: blk.timestamp_seconds(),
+        evt_block_number: blk.number,
+        evt_address: Hex(&log.address).to_string(),
+        group_pool_address: String::new(),
+        bid_id: event.bid_id.to_string(),
+        borrower: event.borrower,
+        receiver: event.receiver,
+        metadata_uri: event.metadata_uri.to_vec(),
+    })
+}
+
+// every bid submitted on a TellerV2 contract used by a tracked pool, keyed like teller_bid_key. A bid can be
+// submitted blocks before a pool accepts it, when store_teller_v2_bids doesn't know the bid yet
+pub fn set_teller_v2_submitted_bids(
+    blk: &eth::Block,
+    teller_v2_bids: &StoreGetString,
+    store: &StoreSetProto<contract::TellerV2SubmittedBid>,
+) {
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            if teller_v2_bids.get_last(teller_key(&log.address)).is_none() {
+                continue;
+            }
+            if let Some(submitted_bid) = submitted_bid(blk, &view.transaction.hash, log) {
+                store.set(log.ordinal, teller_bid_key(&log.address, &submitted_bid.bid_id), &submitted_bid);
+            }
+        }
+    }
+}
+
+// TellerV2 logs of the bids accepted by a tracked pool. The store is read at the end of the block, so the
+// SubmittedBid / AcceptedBid logs emitted before the pool's BorrowerAcceptedFunds in the same transaction are kept.
+// TellerV2 has no default or collateral claim event: a defaulted loan ends with LoanLiquidated, or LoanClosed when
+// the lender claims the collateral (the pool's DefaultedLoanLiquidated), and the collateral moves are logged by the
+// CollateralManager, which isn't tracked
+pub fn map_teller_v2_events(
+    blk: &eth::Block,
+    teller_v2_bids: &StoreGetString,
+    submitted_bids: &StoreGetProto<contract::TellerV2SubmittedBid>,
+) -> contract::TellerV2Events {
+    let mut teller_events = contract::TellerV2Events::default();
+
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(Ok(topic0)) = log.topics.first().map(|topic| <[u8; 32]>::try_from(topic.as_slice())) else {
+                continue;
+            };
+            if !topics::teller_v2::ALL.contains(&topic0) || teller_v2_bids.get_last(teller_key(&log.address)).is_none() {
+                continue;
+            }
+            let pool_of = |bid_id: &BigInt| teller_v2_bids.get_last(teller_bid_key(&log.address, &bid_id.to_string()));
+            let evt_tx_hash = Hex(&view.transaction.hash).to_string();
+            let evt_address = Hex(&log.address).to_string();
+
+            match topic0 {
+                topics::teller_v2::SUBMITTED_BID => {
+                    let Some(mut submitted_bid) = submitted_bid(blk, &view.transaction.hash, log) else { continue };
+                    let Some(group_pool_address) = teller_v2_bids.get_last(teller_bid_key(&log.address, &submitted_bid.bid_id)) else { continue };
+                    submitted_bid.group_pool_address = group_pool_address;
+                    teller_events.teller_v2_submitted_bids.push(submitted_bid);
+                }
+                topics::teller_v2::ACCEPTED_BID => {
+                    let Some(event) = events::AcceptedBid::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    // a bid submitted in an earlier block is emitted with its acceptance, same block submissions are decoded above
+                    if let Some(mut submitted_bid) = submitted_bids
+                        .get_last(teller_bid_key(&log.address, &event.bid_id.to_string()))
+                        .filter(|submitted_bid| submitted_bid.evt_block_number < blk.number)
+                    {
+                        submitted_bid.group_pool_address = group_pool_address.clone();
+                        teller_events.teller_v2_submitted_bids.push(submitted_bid);
+                    }
+                    teller_events.teller_v2_accepted_bids.push(contract::TellerV2AcceptedBid {
+                        evt_tx_hash,
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        evt_address,
+                        group_pool_address,
+                        bid_id: event.bid_id.to_string(),
+                        lender: event.lender,
+                    });
+                }
+                topics::teller_v2::CANCELLED_BID => {
+                    let Some(event) = events::CancelledBid::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    teller_events.teller_v2_cancelled_bids.push(contract::TellerV2CancelledBid {
+                        evt_tx_hash,
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        evt_address,
+                        group_pool_address,
+                        bid_id: event.bid_id.to_string(),
+                    });
+                }
+                topics::teller_v2::MARKET_OWNER_CANCELLED_BID => {
+                    let Some(event) = events::MarketOwnerCancelledBid::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    teller_events
+                        .teller_v2_market_owner_cancelled_bids
+                        .push(contract::TellerV2MarketOwnerCancelledBid {
+                            evt_tx_hash,
+                            evt_index: log.block_index,
+                            evt_ordinal: log.ordinal,
+                            evt_block_time: blk.timestamp_seconds(),
+                            evt_block_number: blk.number,
+                            evt_address,
+                            group_pool_address,
+                            bid_id: event.bid_id.to_string(),
+                        });
+                }
+                topics::teller_v2::LOAN_REPAYMENT => {
+                    let Some(event) = events::LoanRepayment::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    teller_events.teller_v2_loan_repayments.push(contract::TellerV2LoanRepayment {
+                        evt_tx_hash,
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        evt_address,
+                        group_pool_address,
+                        bid_id: event.bid_id.to_string(),
+                    });
+                }
+                topics::teller_v2::LOAN_REPAID => {
+                    let Some(event) = events::LoanRepaid::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    teller_events.teller_v2_loan_repaids.push(contract::TellerV2LoanRepaid {
+                        evt_tx_hash,
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        evt_address,
+                        group_pool_address,
+                        bid_id: event.bid_id.to_string(),
+                    });
+                }
+                topics::teller_v2::LOAN_LIQUIDATED => {
+                    let Some(event) = events::LoanLiquidated::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    teller_events.teller_v2_loan_liquidateds.push(contract::TellerV2LoanLiquidated {
+                        evt_tx_hash,
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        evt_address,
+                        group_pool_address,
+                        bid_id: event.bid_id.to_string(),
+                        liquidator: event.liquidator,
+                    });
+                }
+                topics::teller_v2::LOAN_CLOSED => {
+                    let Some(event) = events::LoanClosed::match_and_decode(log) else { continue };
+                    let Some(group_pool_address) = pool_of(&event.bid_id) else { continue };
+                    teller_events.teller_v2_loan_closeds.push(contract::TellerV2LoanClosed {
+                        evt_tx_hash,
+                        evt_index: log.block_index,
+                        evt_ordinal: log.ordinal,
+                        evt_block_time: blk.timestamp_seconds(),
+                        evt_block_number: blk.number,
+                        evt_address,
+                        group_pool_address,
+                        bid_id: event.bid_id.to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    teller_events
+}
+
+pub fn db_teller_v2_out(teller_events: &contract::TellerV2Events, tables: &mut DatabaseChangeTables) {
+    teller_events.teller_v2_submitted_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_submitted_bid", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
+            .set("borrower", Hex(&evt.borrower).to_string())
+            .set("receiver", Hex(&evt.receiver).to_string())
+            .set("metadata_uri", Hex(&evt.metadata_uri).to_string());
+    });
+    teller_events.teller_v2_accepted_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_accepted_bid", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
+            .set("lender", Hex(&evt.lender).to_string());
+    });
+    teller_events.teller_v2_cancelled_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_cancelled_bid", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_market_owner_cancelled_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_market_owner_cancelled_bid", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_loan_repayments.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_repayment", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_loan_repaids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_repaid", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_loan_liquidateds.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_liquidated", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
+            .set("liquidator", Hex(&evt.liquidator).to_string());
+    });
+    teller_events.teller_v2_loan_closeds.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_closed", [("evt_tx_hash", evt.evt_tx_hash.to_string()),("evt_index", evt.evt_index.to_string())])
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+}
+
+pub fn graph_teller_v2_out(teller_events: &contract::TellerV2Events, tables: &mut EntityChangesTables) {
+    teller_events.teller_v2_submitted_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_submitted_bid", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
+            .set("borrower", Hex(&evt.borrower).to_string())
+            .set("receiver", Hex(&evt.receiver).to_string())
+            .set("metadata_uri", Hex(&evt.metadata_uri).to_string());
+    });
+    teller_events.teller_v2_accepted_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_accepted_bid", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
+            .set("lender", Hex(&evt.lender).to_string());
+    });
+    teller_events.teller_v2_cancelled_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_cancelled_bid", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_market_owner_cancelled_bids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_market_owner_cancelled_bid", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_loan_repayments.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_repayment", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_loan_repaids.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_repaid", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+    teller_events.teller_v2_loan_liquidateds.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_liquidated", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap())
+            .set("liquidator", Hex(&evt.liquidator).to_string());
+    });
+    teller_events.teller_v2_loan_closeds.iter().for_each(|evt| {
+        tables
+            .create_row("teller_v2_loan_closed", format!("{}-{}", evt.evt_tx_hash, evt.evt_index))
+            .set("evt_tx_hash", &evt.evt_tx_hash)
+            .set("evt_index", evt.evt_index)
+            .set("evt_block_time", evt.evt_block_time)
+            .set("evt_block_number", evt.evt_block_number)
+            .set("evt_address", &evt.evt_address)
+            .set("group_pool_address", &evt.group_pool_address)
+            .set("bid_id", BigDecimal::from_str(&evt.bid_id).unwrap());
+    });
+}
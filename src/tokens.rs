@@ -0,0 +1,114 @@
+use crate::pb::contract::v1 as contract;
+use crate::rpc;
+use crate::rpc_failures::status_label;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaString, Deltas, StoreGet, StoreGetProto, StoreSetIfNotExists, StoreSetIfNotExistsString};
+use substreams::Hex;
+use substreams_database_change::tables::Tables as DatabaseChangeTables;
+use substreams_entity_change::tables::Tables as EntityChangesTables;
+
+// store_token_addresses key, the token address
+pub fn token_key(token_address: &[u8]) -> String {
+    Hex(token_address).to_string()
+}
+
+// principal, collateral and shares tokens of the pools initialized in this block, the value is the
+// first group pool they appeared in. set_if_not_exists only emits a delta the first time a token is seen
+pub fn set_token_addresses(events: &contract::Events, store: &StoreSetIfNotExistsString) {
+    events.lendergroup_pool_initializeds.iter().for_each(|evt| {
+        for token_address in [&evt.principal_token_address, &evt.collateral_token_address, &evt.pool_shares_token] {
+            store.set_if_not_exists(evt.evt_ordinal, token_key(token_address), &evt.evt_address);
+        }
+    });
+}
+
+// decimals() of the pool tokens is already read by store_pool_configs
+fn token_decimals(token_address: &[u8], pool_config: &contract::PoolConfig) -> Option<u64> {
+    if token_address == pool_config.principal_token_address.as_slice() {
+        pool_config.principal_token_decimals
+    } else if token_address == pool_config.collateral_token_address.as_slice() {
+        pool_config.collateral_token_decimals
+    } else if token_address == pool_config.pool_shares_token.as_slice() {
+        pool_config.pool_shares_token_decimals
+    } else {
+        None
+    }
+}
+
+// metadata of the tokens seen for the first time in this block, name and symbol read in one RpcBatch
+pub fn map_tokens(
+    block_number: u64,
+    token_addresses_deltas: &Deltas<DeltaString>,
+    pool_configs: &StoreGetProto<contract::PoolConfig>,
+) -> contract::Tokens {
+    let new_tokens: Vec<(&DeltaString, Vec<u8>)> = token_addresses_deltas
+        .deltas
+        .iter()
+        .filter_map(|delta| match hex::decode(&delta.key) {
+            Ok(token_address) => Some((delta, token_address)),
+            Err(_) => {
+                substreams::log::info!("Skipping token metadata, invalid token address {}", delta.key);
+                None
+            }
+        })
+        .collect();
+    let token_addresses: Vec<Vec<u8>> = new_tokens.iter().map(|(_, token_address)| token_address.clone()).collect();
+
+    let fetched = rpc::fetch_token_metadata_from_rpc(&token_addresses);
+
+    contract::Tokens {
+        tokens: new_tokens
+            .iter()
+            .zip(fetched)
+            .map(|((delta, token_address), metadata)| {
+                // the value is the group pool the token appeared in
+                let pool_config = pool_configs.get_last(&delta.new_value).unwrap_or_default();
+                contract::Token {
+                    address: delta.key.clone(),
+                    rpc_enrichment_status: metadata.enrichment_status() as i32,
+                    name: metadata.name,
+                    symbol: metadata.symbol,
+                    decimals: token_decimals(token_address, &pool_config),
+                    block_number,
+                }
+            })
+            .collect(),
+    }
+}
+
+pub fn db_tokens_out(tokens: &contract::Tokens, tables: &mut DatabaseChangeTables) {
+    tokens.tokens.iter().for_each(|token| {
+        let row = tables
+            .upsert_row("token", &token.address)
+            .set("rpc_enrichment_status", status_label(token.rpc_enrichment_status()).to_string())
+            .set("block_number", token.block_number);
+        if let Some(name) = &token.name {
+            row.set("name", name);
+        }
+        if let Some(symbol) = &token.symbol {
+            row.set("symbol", symbol);
+        }
+        if let Some(decimals) = token.decimals {
+            row.set("decimals", decimals);
+        }
+    });
+}
+
+pub fn graph_tokens_out(tokens: &contract::Tokens, tables: &mut EntityChangesTables) {
+    tokens.tokens.iter().for_each(|token| {
+        let row = tables
+            .create_row("token", &token.address)
+            .set("address", &token.address)
+            .set("rpc_enrichment_status", status_label(token.rpc_enrichment_status()).to_string())
+            .set("block_number", BigInt::from(token.block_number));
+        if let Some(name) = &token.name {
+            row.set("name", name);
+        }
+        if let Some(symbol) = &token.symbol {
+            row.set("symbol", symbol);
+        }
+        if let Some(decimals) = token.decimals {
+            row.set("decimals", decimals);
+        }
+    });
+}
@@ -0,0 +1,90 @@
+use hex_literal::hex;
+
+/*
+
+topic0 of the events decoded in map_events, used to dispatch every log to its decoder
+in a single pass (same values as the TOPIC_ID of the generated abi events)
+
+*/
+
+pub mod factory_v1 {
+    use super::hex;
+
+    pub const ADMIN_CHANGED: [u8; 32] = hex!("7e644d79422f17c01e4894b5f4f588d331ebfa28653d42ae832dc59e38c9798f");
+    pub const BEACON_UPGRADED: [u8; 32] = hex!("1cf3b03a6cf19fa2baba4df148e9dcabedea7f8a5c07840e207e5c089be95d3e");
+    pub const DEPLOYED_LENDER_GROUP_CONTRACT: [u8; 32] =
+        hex!("964cb94e9a8348fd6019aabcb4fbc5360f71dbb4fed4e4368a77c16f65653c3a");
+    pub const UPGRADED: [u8; 32] = hex!("bc7cd75a20ee27fd9adebab32041f755214dbc6bffa90cc0225b39da2e5c2d3b");
+}
+
+pub mod erc20 {
+    use super::hex;
+
+    // only matched on the pool shares tokens registered in store_share_tokens
+    pub const TRANSFER: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+}
+
+pub mod lendergroup_v1 {
+    use super::hex;
+
+    pub const BORROWER_ACCEPTED_FUNDS: [u8; 32] =
+        hex!("e235603860e031bbbc9226d101fa83a2a56a9ac8a576441e08de342faed03a58");
+    pub const DEFAULTED_LOAN_LIQUIDATED: [u8; 32] =
+        hex!("274d762f568e6bddf149314ec29fac2cc57609d38374d13f92dad87efa588387");
+    pub const EARNINGS_WITHDRAWN: [u8; 32] = hex!("63ea6893b1630580220db5d5cff7cf64b8667968ad79f60ab3d06e556a51a45e");
+    pub const INITIALIZED: [u8; 32] = hex!("7f26b83ff96e1f2b6a682f133852f6798a09c465da95921460cefb3847402498");
+    pub const LENDER_ADDED_PRINCIPAL: [u8; 32] =
+        hex!("e7464804846ac8c8ab24504d6bf0bbd109b8610ea38bce763f4abf369cb13130");
+    pub const LOAN_REPAID: [u8; 32] = hex!("096eee4238d7fc8b087bfbce1d8c5019025fcdd6db735aa0c1810ab450ccc81d");
+    pub const OWNERSHIP_TRANSFERRED: [u8; 32] =
+        hex!("8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0");
+    pub const PAUSED: [u8; 32] = hex!("62e78cea01bee320cd4e420270b5ea74000d11b0c9f74754ebdbfc544b05a258");
+    pub const POOL_INITIALIZED: [u8; 32] = hex!("5b9b36bf77bd9b7fe2fb0a16f49972464358e061f775e6d4eca8478cf1e85bfb");
+    pub const UNPAUSED: [u8; 32] = hex!("5db9ee0a495bf2e6ff9c91a7834c1ba4fdd244a5e8aa4e537bd38aeae4b073aa");
+
+    pub const ALL: [[u8; 32]; 10] = [
+        BORROWER_ACCEPTED_FUNDS,
+        DEFAULTED_LOAN_LIQUIDATED,
+        EARNINGS_WITHDRAWN,
+        INITIALIZED,
+        LENDER_ADDED_PRINCIPAL,
+        LOAN_REPAID,
+        OWNERSHIP_TRANSFERRED,
+        PAUSED,
+        POOL_INITIALIZED,
+        UNPAUSED,
+    ];
+}
+
+pub mod teller_v2 {
+    use super::hex;
+
+    // decoded by map_teller_v2_events, only on the TellerV2 contracts of the tracked pools
+    pub const ACCEPTED_BID: [u8; 32] = hex!("de9d3bfa8771df6761c0afac2375c88c70a3aa30478e1bd15363294033b470ed");
+    pub const CANCELLED_BID: [u8; 32] = hex!("a0633b09ac3029a6746aa27d4db1407f5f287a10c41a6b2ad2859f4da9b2680b");
+    pub const LOAN_CLOSED: [u8; 32] = hex!("50d3f9b87161fe68d44f1df9c50ec05198e280f710299688dccdd0dea547266c");
+    pub const LOAN_LIQUIDATED: [u8; 32] = hex!("73de9acc561f27528ab0a3b5dd63fefb4e59f95575891299a6f862a787798176");
+    pub const LOAN_REPAID: [u8; 32] = hex!("9a7851747cd7ffb3fe0a32caf3da48b31f27cebe131267051640f8b72fc47186");
+    pub const LOAN_REPAYMENT: [u8; 32] = hex!("68ca97895fe2d09eab47e752271728ade667e72dda27e68c20eaa191a9c2187d");
+    pub const MARKET_OWNER_CANCELLED_BID: [u8; 32] =
+        hex!("f3f271d754f5264e0d143bf9be577d6eba153b0d833bd3a127d7b1a280bb13f1");
+    pub const SUBMITTED_BID: [u8; 32] = hex!("f887b1f393f43fb94c5d50483df4bd410ffbf286128c5f24ff56c580ac7f731c");
+
+    pub const ALL: [[u8; 32]; 8] = [
+        ACCEPTED_BID,
+        CANCELLED_BID,
+        LOAN_CLOSED,
+        LOAN_LIQUIDATED,
+        LOAN_REPAID,
+        LOAN_REPAYMENT,
+        MARKET_OWNER_CANCELLED_BID,
+        SUBMITTED_BID,
+    ];
+}
+
+pub mod uniswap_v3_pool {
+    use super::hex;
+
+    // decoded by map_collateral_prices, only on the Uniswap V3 oracle pools of the tracked pools
+    pub const SWAP: [u8; 32] = hex!("c42079f94a6350d7e6235f29174924f928cc2ac818eb64fed8004e115fbcca67");
+}